@@ -0,0 +1,41 @@
+use criterion::{BatchSize, BenchmarkId, Criterion, black_box, criterion_group, criterion_main};
+use maxlen::{BString, BVec, encoding::Cesu8};
+
+fn bvec_from_slice(c: &mut Criterion) {
+	let mut group = c.benchmark_group("BVec::from_slice");
+	for len in [16, 1024, 65536] {
+		let data: Vec<u64> = (0..len).collect();
+
+		group.bench_with_input(BenchmarkId::new("from_slice", len), &data, |b, data| {
+			b.iter(|| BVec::<u64, 65536>::from_slice(black_box(data)).unwrap())
+		});
+		group.bench_with_input(BenchmarkId::new("to_vec", len), &data, |b, data| {
+			b.iter(|| BVec::<u64, 65536>::from_vec(black_box(data).to_vec()).unwrap())
+		});
+		group.bench_with_input(BenchmarkId::new("rejected", len), &data, |b, data| {
+			b.iter(|| BVec::<u64, 8>::from_slice(black_box(data)).unwrap_err())
+		});
+	}
+	group.finish();
+}
+
+fn bstring_from_str(c: &mut Criterion) {
+	let mut group = c.benchmark_group("BString<Cesu8>");
+	// a mix of ASCII, BMP and supplementary characters
+	let s = "ascii, ąčęėįšųū, 🦀🦀🦀 ".repeat(2048);
+
+	group.bench_function("from_str", |b| {
+		b.iter(|| BString::<{ 1 << 20 }, Cesu8>::from_str(black_box(&s)).unwrap())
+	});
+	group.bench_function("from_string", |b| {
+		b.iter_batched(
+			|| s.clone(),
+			|s| BString::<{ 1 << 20 }, Cesu8>::from_string(s).unwrap(),
+			BatchSize::LargeInput,
+		)
+	});
+	group.finish();
+}
+
+criterion_group!(benches, bvec_from_slice, bstring_from_str);
+criterion_main!(benches);
@@ -1,3 +1,5 @@
+extern crate alloc;
+
 use encoding::Encoding;
 use proc_macro::TokenStream;
 use proc_macro_error::{abort_call_site, proc_macro_error};
@@ -9,8 +11,38 @@ use syn::{
 	parse_macro_input,
 };
 
+// shared with the main crate, which uses the encoding functions too
+#[allow(dead_code)]
 mod encoding;
 
+/// A known encoding: its type name in `maxlen::encoding`, length function and human-readable name
+type EncodingEntry = (&'static str, fn(&str) -> usize, &'static str);
+
+macro_rules! encodings {
+	($($name:ident),* $(,)?) => {
+		&[$((stringify!($name), encoding::$name::length, encoding::$name::NAME)),*]
+	};
+}
+
+/// Every encoding exported from `maxlen::encoding`
+const ENCODINGS: &[EncodingEntry] = encodings![Utf8, Cesu8, MCesu8];
+
+/// Computes the length of `s` in the encoding with the given type name,
+/// returning it along with the human-readable name of the encoding
+fn encoded_length(encoding: &str, s: &str) -> Result<(usize, &'static str), String> {
+	match ENCODINGS.iter().find(|(ty, _, _)| *ty == encoding) {
+		Some((_, length, name)) => Ok((length(s), name)),
+		None => {
+			let known: Vec<&str> = ENCODINGS.iter().map(|(ty, _, _)| *ty).collect();
+			Err(format!(
+				"Unknown encoding {:?}, expected one of: {}",
+				encoding,
+				known.join(", ")
+			))
+		}
+	}
+}
+
 struct BStrInput {
 	max: usize,
 	encoding: Ident,
@@ -48,17 +80,19 @@ impl Parse for BStrInput {
 pub fn bstr(input: TokenStream) -> TokenStream {
 	let input = parse_macro_input!(input as BStrInput);
 
-	let length = match input.encoding.to_string().as_str() {
-		"Utf8" => encoding::Utf8::length,
-		"Cesu8" => encoding::Cesu8::length,
-		"MCesu8" => encoding::MCesu8::length,
-		other => {
-			abort_call_site!("Unknown encoding {:?}", other);
-		}
+	let value = input.str.value();
+	let (length, name) = match encoded_length(&input.encoding.to_string(), &value) {
+		Ok(v) => v,
+		Err(e) => abort_call_site!("{}", e),
 	};
 
-	if length(&input.str.value()) > input.max {
-		abort_call_site!("Length exceeded! Max length {}", input.max);
+	if length > input.max {
+		abort_call_site!(
+			"Length exceeded! {} length {} exceeded maximum of {}",
+			name,
+			length,
+			input.max
+		);
 	}
 
 	let str = input.str;
@@ -71,3 +105,24 @@ pub fn bstr(input: TokenStream) -> TokenStream {
 	}
 	.into()
 }
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn test_encoded_length() {
+		assert_eq!(encoded_length("Utf8", "a\0😀"), Ok((6, "UTF-8")));
+		assert_eq!(encoded_length("Cesu8", "a\0😀"), Ok((8, "CESU-8")));
+		assert_eq!(
+			encoded_length("MCesu8", "a\0😀"),
+			Ok((9, "Modified CESU-8"))
+		);
+
+		let err = encoded_length("Utf16", "a").unwrap_err();
+		assert_eq!(
+			err,
+			"Unknown encoding \"Utf16\", expected one of: Utf8, Cesu8, MCesu8"
+		);
+	}
+}
@@ -0,0 +1,132 @@
+use crate::BSlice;
+use std::io::{self, BufRead, Cursor, Read, Seek, SeekFrom};
+
+/// A [`Cursor`] over a bounded byte slice.
+///
+/// Provides [`Read`], [`BufRead`] and [`Seek`] with standard cursor semantics,
+/// while keeping the `MAX` bound of the underlying slice.
+///
+/// ```
+/// # use maxlen::{bslice, BCursor, BSlice};
+/// # use std::io::{Read, Seek, SeekFrom};
+/// let s: &BSlice<u8, 8> = bslice![1, 2, 3, 4];
+/// let mut c = BCursor::new(s);
+/// let mut buf = [0; 2];
+/// c.read_exact(&mut buf).unwrap();
+/// assert_eq!(buf, [1, 2]);
+/// c.seek(SeekFrom::End(-1)).unwrap();
+/// c.read_exact(&mut buf[..1]).unwrap();
+/// assert_eq!(buf[0], 4);
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct BCursor<'a, const MAX: usize> {
+	inner: Cursor<&'a BSlice<u8, MAX>>,
+}
+
+impl<'a, const MAX: usize> BCursor<'a, MAX> {
+	/// Creates a new cursor at the start of the given slice.
+	///
+	/// See [`Cursor::new`] for more information.
+	pub fn new(inner: &'a BSlice<u8, MAX>) -> Self {
+		Self {
+			inner: Cursor::new(inner),
+		}
+	}
+	/// Gives the underlying bounded slice.
+	pub fn into_inner(self) -> &'a BSlice<u8, MAX> {
+		self.inner.into_inner()
+	}
+	/// Gets a reference to the underlying bounded slice.
+	pub fn get_ref(&self) -> &'a BSlice<u8, MAX> {
+		self.inner.get_ref()
+	}
+	/// Returns the current position of this cursor.
+	///
+	/// See [`Cursor::position`] for more information.
+	pub fn position(&self) -> u64 {
+		self.inner.position()
+	}
+	/// Sets the position of this cursor.
+	///
+	/// See [`Cursor::set_position`] for more information.
+	pub fn set_position(&mut self, pos: u64) {
+		self.inner.set_position(pos)
+	}
+	/// Returns the part of the slice that has not been read yet.
+	pub fn remaining_bslice(&self) -> &'a BSlice<u8, MAX> {
+		let slice = self.get_ref();
+		let pos = self.position().min(slice.len() as u64) as usize;
+
+		// a subslice is never longer than the original
+		unsafe { BSlice::from_slice_unchecked(&slice[pos..]) }
+	}
+}
+
+impl<const MAX: usize> Read for BCursor<'_, MAX> {
+	fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+		self.inner.read(buf)
+	}
+	fn read_exact(&mut self, buf: &mut [u8]) -> io::Result<()> {
+		self.inner.read_exact(buf)
+	}
+}
+impl<const MAX: usize> BufRead for BCursor<'_, MAX> {
+	fn fill_buf(&mut self) -> io::Result<&[u8]> {
+		self.inner.fill_buf()
+	}
+	fn consume(&mut self, amt: usize) {
+		self.inner.consume(amt)
+	}
+}
+impl<const MAX: usize> Seek for BCursor<'_, MAX> {
+	fn seek(&mut self, pos: SeekFrom) -> io::Result<u64> {
+		self.inner.seek(pos)
+	}
+	fn stream_position(&mut self) -> io::Result<u64> {
+		self.inner.stream_position()
+	}
+}
+impl<'a, const MAX: usize> From<&'a BSlice<u8, MAX>> for BCursor<'a, MAX> {
+	fn from(value: &'a BSlice<u8, MAX>) -> Self {
+		Self::new(value)
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use crate::*;
+	use std::io::{BufRead, Read, Seek, SeekFrom};
+
+	#[test]
+	fn test_read_seek() {
+		let s: &BSlice<u8, 16> = bslice![0, 1, 2, 3, 4, 5, 6, 7, 8, 9];
+		let mut c = BCursor::new(s);
+
+		let mut buf = [0; 4];
+		assert_eq!(c.read(&mut buf).unwrap(), 4);
+		assert_eq!(buf, [0, 1, 2, 3]);
+		assert_eq!(c.read(&mut buf).unwrap(), 4);
+		assert_eq!(buf, [4, 5, 6, 7]);
+		assert_eq!(c.read(&mut buf).unwrap(), 2);
+		assert_eq!(buf[..2], [8, 9]);
+		assert_eq!(c.read(&mut buf).unwrap(), 0);
+		assert!(c.remaining_bslice().is_empty());
+
+		assert_eq!(c.seek(SeekFrom::Start(3)).unwrap(), 3);
+		assert_eq!(c.remaining_bslice(), [3, 4, 5, 6, 7, 8, 9]);
+		assert_eq!(c.seek(SeekFrom::Current(-2)).unwrap(), 1);
+		assert_eq!(c.fill_buf().unwrap()[0], 1);
+		c.consume(2);
+		assert_eq!(c.position(), 3);
+		assert_eq!(c.seek(SeekFrom::End(-1)).unwrap(), 9);
+		assert_eq!(c.read(&mut buf).unwrap(), 1);
+		assert_eq!(buf[0], 9);
+		assert!(c.seek(SeekFrom::Current(-20)).is_err());
+
+		// seeking past the end is allowed, reading from there gives nothing
+		c.set_position(100);
+		assert_eq!(c.read(&mut buf).unwrap(), 0);
+		assert!(c.remaining_bslice().is_empty());
+		assert_eq!(c.into_inner(), s);
+	}
+}
@@ -0,0 +1,303 @@
+use alloc::vec::Vec;
+use core::{
+	borrow::Borrow,
+	ops::{Deref, DerefMut},
+};
+
+use crate::{BSlice, BVec, LengthExceeded, LengthOutOfBounds, const_checks};
+
+/// Bounded [`Vec`] with both a lower and an upper limit.
+///
+/// Guaranteed to have at least `MIN` and at most `MAX` elements.
+///
+/// ```
+/// # use maxlen::{bvec, BMinVec};
+/// let mut v = BMinVec::<u8, 1, 3>::from_bvec(bvec![1, 2]).unwrap();
+/// v.push(3).unwrap();
+/// assert!(v.push(4).is_err());
+/// assert_eq!(v.pop().unwrap(), 3);
+/// assert_eq!(v.pop().unwrap(), 2);
+/// assert!(v.pop().is_err());
+/// assert_eq!(v, [1]);
+///
+/// // let _ = BMinVec::<u8, 4, 2>::from_vec(vec![]); // will not compile, MIN > MAX
+/// ```
+#[derive(Debug, Hash)]
+pub struct BMinVec<T, const MIN: usize, const MAX: usize> {
+	s: Vec<T>,
+}
+
+impl<T, const MIN: usize, const MAX: usize> BMinVec<T, MIN, MAX> {
+	/// Creates a `BMinVec<T, MIN, MAX>` from a `Vec<T>` without any checks.
+	///
+	/// # Safety
+	///
+	/// The caller is responsible for making sure that the vector has at least `MIN`
+	/// and at most `MAX` elements.
+	pub const unsafe fn from_vec_unchecked(s: Vec<T>) -> Self {
+		let () = <const_checks::Pair<MAX, MIN> as const_checks::AssertGe>::VALID;
+
+		Self { s }
+	}
+	/// Creates a `BMinVec<T, MIN, MAX>` from a `Vec<T>`, performing a runtime check of both bounds.
+	pub fn from_vec(s: Vec<T>) -> Result<Self, LengthOutOfBounds> {
+		Self::from_bvec(BVec::from_vec(s)?)
+	}
+	/// Creates a `BMinVec<T, MIN, MAX>` from a `BVec<T, MAX>`, performing a runtime check.
+	pub fn from_bvec(s: BVec<T, MAX>) -> Result<Self, LengthOutOfBounds> {
+		if s.len() < MIN {
+			return Err(LengthOutOfBounds::BelowMinimum {
+				length: s.len(),
+				minimum: MIN,
+			});
+		}
+
+		Ok(unsafe { Self::from_vec_unchecked(s.into_inner()) })
+	}
+	/// Gives the inner [`BVec<T, MAX>`], dropping the lower bound.
+	pub fn into_bvec(self) -> BVec<T, MAX> {
+		unsafe { BVec::from_vec_unchecked(self.s) }
+	}
+	/// Gives the inner [`Vec<T>`].
+	pub fn into_inner(self) -> Vec<T> {
+		self.s
+	}
+	/// Inserts an element at position `index`, performing a runtime check.
+	///
+	/// See [`Vec::insert`] for more information.
+	pub fn insert(&mut self, index: usize, value: T) -> Result<(), LengthExceeded> {
+		self.check_push()?;
+		self.s.insert(index, value);
+
+		Ok(())
+	}
+	/// Removes the last element and returns it, or an error if the vector already has only `MIN` elements.
+	///
+	/// See [`Vec::pop`] for more information.
+	pub fn pop(&mut self) -> Result<T, LengthOutOfBounds> {
+		self.check_remove()?;
+
+		// the vector has more than `MIN` elements, so it is not empty
+		Ok(self.s.pop().unwrap())
+	}
+	/// Appends an element to the back of the vector, performing a runtime check.
+	///
+	/// See [`Vec::push`] for more information.
+	pub fn push(&mut self, value: T) -> Result<(), LengthExceeded> {
+		self.check_push()?;
+		self.s.push(value);
+
+		Ok(())
+	}
+	/// Removes and returns the element at `index`, or an error if the vector already has only `MIN` elements.
+	///
+	/// # Panics
+	///
+	/// Panics if `index` is out of bounds, just like [`Vec::remove`].
+	pub fn remove(&mut self, index: usize) -> Result<T, LengthOutOfBounds> {
+		if index < self.s.len() {
+			self.check_remove()?;
+		}
+
+		Ok(self.s.remove(index))
+	}
+	fn check_push(&self) -> Result<(), LengthExceeded> {
+		if self.s.len() >= MAX {
+			return Err(LengthExceeded {
+				length: self.s.len() + 1,
+				maximum: MAX,
+				encoding: LengthExceeded::ELEMENTS,
+			});
+		}
+
+		Ok(())
+	}
+	fn check_remove(&self) -> Result<(), LengthOutOfBounds> {
+		if self.s.len() <= MIN {
+			return Err(LengthOutOfBounds::BelowMinimum {
+				length: self.s.len().saturating_sub(1),
+				minimum: MIN,
+			});
+		}
+
+		Ok(())
+	}
+}
+
+// Trait implementations relating BMinVec and BVec
+///////////////////////////////////////////////////
+
+impl<T, const MIN: usize, const MAX: usize> TryFrom<BVec<T, MAX>> for BMinVec<T, MIN, MAX> {
+	type Error = LengthOutOfBounds;
+
+	fn try_from(value: BVec<T, MAX>) -> Result<Self, Self::Error> {
+		Self::from_bvec(value)
+	}
+}
+impl<T, const MIN: usize, const MAX: usize> From<BMinVec<T, MIN, MAX>> for BVec<T, MAX> {
+	fn from(value: BMinVec<T, MIN, MAX>) -> Self {
+		value.into_bvec()
+	}
+}
+
+// Trait implementations mirroring standard Vec
+///////////////////////////////////////////////
+
+impl<T, const MIN: usize, const MAX: usize> Deref for BMinVec<T, MIN, MAX> {
+	type Target = BSlice<T, MAX>;
+
+	fn deref(&self) -> &Self::Target {
+		unsafe { BSlice::from_slice_unchecked(&self.s) }
+	}
+}
+impl<T, const MIN: usize, const MAX: usize> DerefMut for BMinVec<T, MIN, MAX> {
+	fn deref_mut(&mut self) -> &mut Self::Target {
+		unsafe { BSlice::from_slice_mut_unchecked(&mut self.s) }
+	}
+}
+impl<T, const MIN: usize, const MAX: usize> AsRef<BSlice<T, MAX>> for BMinVec<T, MIN, MAX> {
+	fn as_ref(&self) -> &BSlice<T, MAX> {
+		self
+	}
+}
+impl<T, const MIN: usize, const MAX: usize> AsRef<[T]> for BMinVec<T, MIN, MAX> {
+	fn as_ref(&self) -> &[T] {
+		self
+	}
+}
+impl<T, const MIN: usize, const MAX: usize> Borrow<BSlice<T, MAX>> for BMinVec<T, MIN, MAX> {
+	fn borrow(&self) -> &BSlice<T, MAX> {
+		self
+	}
+}
+impl<T, const MIN: usize, const MAX: usize> Borrow<[T]> for BMinVec<T, MIN, MAX> {
+	fn borrow(&self) -> &[T] {
+		self
+	}
+}
+impl<T: Clone, const MIN: usize, const MAX: usize> Clone for BMinVec<T, MIN, MAX> {
+	fn clone(&self) -> Self {
+		Self { s: self.s.clone() }
+	}
+}
+impl<T, const MIN: usize, const MAX: usize> TryFrom<Vec<T>> for BMinVec<T, MIN, MAX> {
+	type Error = LengthOutOfBounds;
+
+	fn try_from(value: Vec<T>) -> Result<Self, Self::Error> {
+		Self::from_vec(value)
+	}
+}
+impl<T, const MIN: usize, const MAX: usize> From<BMinVec<T, MIN, MAX>> for Vec<T> {
+	fn from(value: BMinVec<T, MIN, MAX>) -> Self {
+		value.into_inner()
+	}
+}
+impl<T, const MIN: usize, const MAX: usize> IntoIterator for BMinVec<T, MIN, MAX> {
+	type Item = T;
+	type IntoIter = alloc::vec::IntoIter<T>;
+
+	fn into_iter(self) -> Self::IntoIter {
+		self.s.into_iter()
+	}
+}
+impl<'a, T, const MIN: usize, const MAX: usize> IntoIterator for &'a BMinVec<T, MIN, MAX> {
+	type Item = &'a T;
+	type IntoIter = core::slice::Iter<'a, T>;
+
+	fn into_iter(self) -> Self::IntoIter {
+		self.iter()
+	}
+}
+impl<'a, T, const MIN: usize, const MAX: usize> IntoIterator for &'a mut BMinVec<T, MIN, MAX> {
+	type Item = &'a mut T;
+	type IntoIter = core::slice::IterMut<'a, T>;
+
+	fn into_iter(self) -> Self::IntoIter {
+		self.iter_mut()
+	}
+}
+impl<T: PartialEq<U>, U, const MIN1: usize, const MAX1: usize, const MIN2: usize, const MAX2: usize>
+	PartialEq<BMinVec<U, MIN2, MAX2>> for BMinVec<T, MIN1, MAX1>
+{
+	fn eq(&self, other: &BMinVec<U, MIN2, MAX2>) -> bool {
+		(**self).eq(&**other)
+	}
+}
+impl<T: Eq, const MIN: usize, const MAX: usize> Eq for BMinVec<T, MIN, MAX> {}
+impl<T: PartialEq<U>, U, const MIN: usize, const MAX: usize> PartialEq<[U]>
+	for BMinVec<T, MIN, MAX>
+{
+	fn eq(&self, other: &[U]) -> bool {
+		(**self).eq(other)
+	}
+}
+impl<T: PartialEq<U>, U, const MIN: usize, const MAX: usize, const N: usize> PartialEq<[U; N]>
+	for BMinVec<T, MIN, MAX>
+{
+	fn eq(&self, other: &[U; N]) -> bool {
+		(**self).eq(other)
+	}
+}
+impl<T: PartialOrd, const MIN: usize, const MAX: usize> PartialOrd for BMinVec<T, MIN, MAX> {
+	fn partial_cmp(&self, other: &Self) -> Option<core::cmp::Ordering> {
+		self.s.partial_cmp(&other.s)
+	}
+}
+impl<T: Ord, const MIN: usize, const MAX: usize> Ord for BMinVec<T, MIN, MAX> {
+	fn cmp(&self, other: &Self) -> core::cmp::Ordering {
+		self.s.cmp(&other.s)
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use crate::*;
+	use alloc::vec;
+
+	#[test]
+	fn test_push_pop() {
+		let mut v = BMinVec::<u8, 2, 4>::from_vec(vec![1, 2]).unwrap();
+		// underflow
+		let err = v.pop().unwrap_err();
+		assert!(matches!(
+			err,
+			LengthOutOfBounds::BelowMinimum {
+				length: 1,
+				minimum: 2
+			}
+		));
+		assert!(v.remove(0).is_err());
+		assert_eq!(v, [1, 2]);
+
+		v.push(3).unwrap();
+		v.insert(0, 0).unwrap();
+		assert_eq!(v, [0, 1, 2, 3]);
+		// overflow
+		let err = v.push(4).unwrap_err();
+		assert_eq!((err.length, err.maximum), (5, 4));
+		assert!(v.insert(0, 4).is_err());
+		assert_eq!(v, [0, 1, 2, 3]);
+
+		assert_eq!(v.remove(0).unwrap(), 0);
+		assert_eq!(v.pop().unwrap(), 3);
+		assert!(v.pop().is_err());
+		assert_eq!(v.into_inner(), [1, 2]);
+	}
+
+	#[test]
+	fn test_from_vec() {
+		let err = BMinVec::<u8, 2, 4>::from_vec(vec![1]).unwrap_err();
+		assert!(matches!(
+			err,
+			LengthOutOfBounds::BelowMinimum {
+				length: 1,
+				minimum: 2
+			}
+		));
+		let err = BMinVec::<u8, 2, 4>::try_from(vec![0; 5]).unwrap_err();
+		assert!(matches!(err, LengthOutOfBounds::LengthExceeded(e) if e.length == 5));
+
+		let v = BMinVec::<u8, 2, 4>::try_from(bvec![1, 2, 3]).unwrap();
+		assert_eq!(BVec::from(v), [1, 2, 3]);
+	}
+}
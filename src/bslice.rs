@@ -1,15 +1,24 @@
 use crate::{BVec, LengthExceeded, const_checks};
-use std::{
-	borrow::{Borrow, BorrowMut, Cow},
+use alloc::{
+	borrow::{Cow, ToOwned},
+	boxed::Box,
 	collections::VecDeque,
-	io::{BufRead, Read, Write},
-	mem::transmute,
+	rc::Rc,
+	sync::Arc,
+	vec::Vec,
+};
+use core::{
+	borrow::{Borrow, BorrowMut},
 	ops::{
 		Bound, Deref, DerefMut, Index, IndexMut, Range, RangeFrom, RangeFull, RangeInclusive,
 		RangeTo, RangeToInclusive,
 	},
-	rc::Rc,
-	sync::Arc,
+	slice::GetDisjointMutError,
+};
+#[cfg(feature = "std")]
+use std::{
+	io::{BufRead, Read, Write},
+	mem::transmute,
 };
 
 /// Bounded [`[T]`][slice].
@@ -51,7 +60,7 @@ impl<T, const MAX: usize> BSlice<T, MAX> {
 	///
 	/// The caller is responsible for making sure that the slice is definitely not longer than `MAX` elements.
 	pub const unsafe fn from_slice_unchecked(s: &[T]) -> &Self {
-		unsafe { std::mem::transmute(s) }
+		unsafe { core::mem::transmute(s) }
 	}
 	/// Creates a `&mut BSlice<T, MAX>` from a mutable slice without any checks.
 	///
@@ -59,7 +68,7 @@ impl<T, const MAX: usize> BSlice<T, MAX> {
 	///
 	/// The caller is responsible for making sure that the slice is definitely not longer than `MAX` elements.
 	pub const unsafe fn from_slice_mut_unchecked(s: &mut [T]) -> &mut Self {
-		unsafe { std::mem::transmute(s) }
+		unsafe { core::mem::transmute(s) }
 	}
 	/// Creates a `&BSlice<T, MAX>` from a slice, performing a runtime check.
 	pub fn from_slice(s: &[T]) -> Result<&Self, LengthExceeded> {
@@ -67,6 +76,7 @@ impl<T, const MAX: usize> BSlice<T, MAX> {
 			return Err(LengthExceeded {
 				length: s.len(),
 				maximum: MAX,
+				encoding: LengthExceeded::ELEMENTS,
 			});
 		}
 
@@ -78,6 +88,7 @@ impl<T, const MAX: usize> BSlice<T, MAX> {
 			return Err(LengthExceeded {
 				length: s.len(),
 				maximum: MAX,
+				encoding: LengthExceeded::ELEMENTS,
 			});
 		}
 
@@ -86,14 +97,14 @@ impl<T, const MAX: usize> BSlice<T, MAX> {
 	/// Constructs a `&BSlice<T, MAX>` from a `&[T; N]` using a compile-time check
 	pub const fn from_array<const N: usize>(v: &[T; N]) -> &BSlice<T, MAX> {
 		// compile time check
-		_ = <const_checks::Pair<MAX, N> as const_checks::AssertGe>::VALID;
+		let () = <const_checks::Pair<MAX, N> as const_checks::AssertGe>::VALID;
 
 		unsafe { BSlice::from_slice_unchecked(v) }
 	}
 	/// Constructs a `&mut BSlice<T, MAX>` from a `&mut [T; N]` using a compile-time check
 	pub const fn from_array_mut<const N: usize>(v: &mut [T; N]) -> &mut BSlice<T, MAX> {
 		// compile time check
-		_ = <const_checks::Pair<MAX, N> as const_checks::AssertGe>::VALID;
+		let () = <const_checks::Pair<MAX, N> as const_checks::AssertGe>::VALID;
 
 		unsafe { BSlice::from_slice_mut_unchecked(v) }
 	}
@@ -103,7 +114,7 @@ impl<T, const MAX: usize> BSlice<T, MAX> {
 	/// asserted at compile time that the new `MAX` is bigger than before.
 	pub const fn relax_max<const MAX2: usize>(&self) -> &BSlice<T, MAX2> {
 		// assert that MAX2 >= MAX at compile time
-		_ = <const_checks::Pair<MAX2, MAX> as const_checks::AssertGe>::VALID;
+		let () = <const_checks::Pair<MAX2, MAX> as const_checks::AssertGe>::VALID;
 
 		unsafe { BSlice::from_slice_unchecked(&self.s) }
 	}
@@ -119,7 +130,7 @@ impl<T, const MAX: usize> BSlice<T, MAX> {
 	/// asserted at compile time that the new `MAX` is bigger than before.
 	pub const fn relax_max_mut<const MAX2: usize>(&mut self) -> &mut BSlice<T, MAX2> {
 		// assert that MAX2 >= MAX at compile time
-		_ = <const_checks::Pair<MAX2, MAX> as const_checks::AssertGe>::VALID;
+		let () = <const_checks::Pair<MAX2, MAX> as const_checks::AssertGe>::VALID;
 
 		unsafe { BSlice::from_slice_mut_unchecked(&mut self.s) }
 	}
@@ -131,6 +142,373 @@ impl<T, const MAX: usize> BSlice<T, MAX> {
 	) -> Result<&mut BSlice<T, MAX2>, LengthExceeded> {
 		BSlice::from_slice_mut(self)
 	}
+	/// Copies the slice into a new [`BVec`].
+	///
+	/// This is the same as [`ToOwned::to_owned`].
+	pub fn to_bvec(&self) -> BVec<T, MAX>
+	where
+		T: Clone,
+	{
+		self.to_owned()
+	}
+	/// Converts a boxed slice into a [`BVec`] without copying or reallocating.
+	///
+	/// See [`slice::into_vec`] for more information.
+	pub fn into_bvec(self: Box<Self>) -> BVec<T, MAX> {
+		let s = unsafe { Box::from_raw(Box::into_raw(self) as *mut [T]) };
+
+		unsafe { BVec::from_vec_unchecked(s.into_vec()) }
+	}
+	/// Returns the first `n` elements, or the whole slice if it is shorter than that.
+	pub fn take(&self, n: usize) -> &Self {
+		unsafe { Self::from_slice_unchecked(&self.s[..n.min(self.s.len())]) }
+	}
+	/// Returns the slice without its first `n` elements, or an empty slice if it is shorter than that.
+	pub fn skip(&self, n: usize) -> &Self {
+		unsafe { Self::from_slice_unchecked(&self.s[n.min(self.s.len())..]) }
+	}
+	/// Divides one slice into two at an index, returning `None` if `mid` is out of bounds.
+	///
+	/// See [`slice::split_at_checked`] for more information.
+	pub fn split_at_checked(&self, mid: usize) -> Option<(&Self, &Self)> {
+		let (l, r) = self.s.split_at_checked(mid)?;
+
+		unsafe { Some((Self::from_slice_unchecked(l), Self::from_slice_unchecked(r))) }
+	}
+	/// Divides the slice into a fixed-size array prefix and a bounded tail.
+	///
+	/// # Panics
+	///
+	/// Panics if the slice is shorter than `N`.
+	///
+	/// ```
+	/// # use maxlen::{bslice, BSlice};
+	/// let packet: &BSlice<u8, 16> = bslice![0xCA, 0xFE, 1, 2, 3];
+	/// let (magic, body) = packet.split_array_ref::<2>();
+	/// assert_eq!(magic, &[0xCA, 0xFE]);
+	/// assert_eq!(body, [1, 2, 3]);
+	/// ```
+	pub fn split_array_ref<const N: usize>(&self) -> (&[T; N], &Self) {
+		let (head, tail) = self
+			.s
+			.split_first_chunk::<N>()
+			.expect("slice is shorter than the array");
+
+		(head, unsafe { Self::from_slice_unchecked(tail) })
+	}
+	/// Divides the slice into a bounded head and a fixed-size array suffix.
+	///
+	/// # Panics
+	///
+	/// Panics if the slice is shorter than `N`.
+	pub fn rsplit_array_ref<const N: usize>(&self) -> (&Self, &[T; N]) {
+		let (head, tail) = self
+			.s
+			.split_last_chunk::<N>()
+			.expect("slice is shorter than the array");
+
+		(unsafe { Self::from_slice_unchecked(head) }, tail)
+	}
+	/// Transmutes the slice to a slice of another type, ensuring alignment of the types is maintained.
+	///
+	/// `U` must be at least as big as `T` (or zero-sized), so that the middle part can't have
+	/// more elements than the original slice. This is checked at compile time.
+	///
+	/// See [`slice::align_to`] for more information.
+	///
+	/// # Safety
+	///
+	/// Same as [`slice::align_to`].
+	pub unsafe fn align_to<U>(&self) -> (&Self, &BSlice<U, MAX>, &Self) {
+		// compile time check
+		const {
+			assert!(
+				size_of::<U>() == 0 || size_of::<U>() >= size_of::<T>(),
+				"the target type must not be smaller than the original"
+			)
+		};
+
+		let (prefix, middle, suffix) = unsafe { self.s.align_to::<U>() };
+
+		// the prefix and suffix are subslices, and the middle can't have more elements than the original
+		unsafe {
+			(
+				Self::from_slice_unchecked(prefix),
+				BSlice::from_slice_unchecked(middle),
+				Self::from_slice_unchecked(suffix),
+			)
+		}
+	}
+	/// Returns `true` if the slice contains an element with the given value.
+	///
+	/// See [`slice::contains`] for more information.
+	pub fn contains(&self, x: &T) -> bool
+	where
+		T: PartialEq,
+	{
+		self.s.contains(x)
+	}
+	/// Returns `true` if `needle` is a prefix of the slice.
+	///
+	/// Accepts anything viewable as a slice, including bounded slices with any `MAX`.
+	///
+	/// See [`slice::starts_with`] for more information.
+	///
+	/// ```
+	/// # use maxlen::{bslice, BSlice};
+	/// let packet: &BSlice<u8, 16> = bslice![0xCA, 0xFE, 1, 2];
+	/// let magic: &BSlice<u8, 2> = bslice![0xCA, 0xFE];
+	/// assert!(packet.starts_with(magic));
+	/// assert!(packet.ends_with(&[1, 2]));
+	/// ```
+	pub fn starts_with<N: AsRef<[T]> + ?Sized>(&self, needle: &N) -> bool
+	where
+		T: PartialEq,
+	{
+		self.s.starts_with(needle.as_ref())
+	}
+	/// Returns `true` if `needle` is a suffix of the slice.
+	///
+	/// Accepts anything viewable as a slice, including bounded slices with any `MAX`.
+	///
+	/// See [`slice::ends_with`] for more information.
+	pub fn ends_with<N: AsRef<[T]> + ?Sized>(&self, needle: &N) -> bool
+	where
+		T: PartialEq,
+	{
+		self.s.ends_with(needle.as_ref())
+	}
+	/// Returns mutable references to many indices at once,
+	/// or an error if any index is out of bounds or two of them overlap.
+	///
+	/// See [`slice::get_disjoint_mut`] for more information.
+	pub fn get_disjoint_mut<const N: usize>(
+		&mut self,
+		indices: [usize; N],
+	) -> Result<[&mut T; N], GetDisjointMutError> {
+		self.s.get_disjoint_mut(indices)
+	}
+	/// Fills the slice with elements by cloning `value`.
+	///
+	/// See [`slice::fill`] for more information.
+	pub fn fill(&mut self, value: T)
+	where
+		T: Clone,
+	{
+		self.s.fill(value)
+	}
+	/// Fills the slice with elements returned by calling a closure repeatedly.
+	///
+	/// See [`slice::fill_with`] for more information.
+	pub fn fill_with<F>(&mut self, f: F)
+	where
+		F: FnMut() -> T,
+	{
+		self.s.fill_with(f)
+	}
+	/// Returns the index of the partition point according to the given predicate.
+	///
+	/// See [`slice::partition_point`] for more information.
+	pub fn partition_point<F>(&self, pred: F) -> usize
+	where
+		F: FnMut(&T) -> bool,
+	{
+		self.s.partition_point(pred)
+	}
+	/// Divides a partitioned slice at its [partition point](Self::partition_point),
+	/// giving the elements matching `pred` first.
+	///
+	/// ```
+	/// # use maxlen::{bslice, BSlice};
+	/// let sorted: &BSlice<u32, 8> = bslice![1, 2, 3, 5, 8, 13];
+	/// let (small, big) = sorted.partition(|&x| x < 5);
+	/// assert_eq!(small, [1, 2, 3]);
+	/// assert_eq!(big, [5, 8, 13]);
+	/// ```
+	pub fn partition<F>(&self, pred: F) -> (&Self, &Self)
+	where
+		F: FnMut(&T) -> bool,
+	{
+		let (l, r) = self.s.split_at(self.s.partition_point(pred));
+
+		unsafe { (Self::from_slice_unchecked(l), Self::from_slice_unchecked(r)) }
+	}
+	/// Returns an iterator over subslices separated by elements that match `pred`.
+	///
+	/// See [`slice::split`] for more information.
+	pub fn split<F>(&self, pred: F) -> impl DoubleEndedIterator<Item = &Self>
+	where
+		F: FnMut(&T) -> bool,
+	{
+		// subslices are never longer than the original
+		self.s
+			.split(pred)
+			.map(|s| unsafe { Self::from_slice_unchecked(s) })
+	}
+	/// Returns an iterator over subslices separated by elements that match `pred`,
+	/// starting at the end of the slice and working backwards.
+	///
+	/// See [`slice::rsplit`] for more information.
+	pub fn rsplit<F>(&self, pred: F) -> impl DoubleEndedIterator<Item = &Self>
+	where
+		F: FnMut(&T) -> bool,
+	{
+		self.s
+			.rsplit(pred)
+			.map(|s| unsafe { Self::from_slice_unchecked(s) })
+	}
+	/// Returns an iterator over subslices separated by elements that match `pred`,
+	/// limited to returning at most `n` items.
+	///
+	/// See [`slice::splitn`] for more information.
+	pub fn splitn<F>(&self, n: usize, pred: F) -> impl Iterator<Item = &Self>
+	where
+		F: FnMut(&T) -> bool,
+	{
+		self.s
+			.splitn(n, pred)
+			.map(|s| unsafe { Self::from_slice_unchecked(s) })
+	}
+	/// Returns an iterator over subslices separated by elements that match `pred`,
+	/// limited to returning at most `n` items, starting at the end of the slice.
+	///
+	/// See [`slice::rsplitn`] for more information.
+	pub fn rsplitn<F>(&self, n: usize, pred: F) -> impl Iterator<Item = &Self>
+	where
+		F: FnMut(&T) -> bool,
+	{
+		self.s
+			.rsplitn(n, pred)
+			.map(|s| unsafe { Self::from_slice_unchecked(s) })
+	}
+	/// Splits the slice into a slice of `N`-element arrays, starting at the beginning of the slice,
+	/// and a remainder slice with length strictly less than `N`.
+	///
+	/// See [`slice::as_chunks`] for more information.
+	///
+	/// # Panics
+	///
+	/// Panics if `N` is 0.
+	///
+	/// ```
+	/// # use maxlen::{bslice, BSlice};
+	/// let s: &BSlice<u8, 8> = bslice![1, 2, 3, 4, 5];
+	/// let (chunks, remainder) = s.as_chunks::<2>();
+	/// assert_eq!(chunks, [[1, 2], [3, 4]]);
+	/// assert_eq!(remainder, [5]);
+	/// ```
+	pub fn as_chunks<const N: usize>(&self) -> (&BSlice<[T; N], MAX>, &Self) {
+		let (chunks, remainder) = self.s.as_chunks::<N>();
+
+		// there are never more chunks than elements in the original
+		unsafe {
+			(
+				BSlice::from_slice_unchecked(chunks),
+				Self::from_slice_unchecked(remainder),
+			)
+		}
+	}
+	/// Returns an iterator over `chunk_size` elements of the slice at a time, starting at the beginning of the slice.
+	///
+	/// See [`slice::chunks`] for more information.
+	pub fn bchunks(
+		&self,
+		chunk_size: usize,
+	) -> impl DoubleEndedIterator<Item = &Self> + ExactSizeIterator {
+		// chunks are never longer than the original
+		self.s
+			.chunks(chunk_size)
+			.map(|s| unsafe { Self::from_slice_unchecked(s) })
+	}
+	/// Returns an iterator over `chunk_size` elements of the slice at a time, starting at the beginning of the slice.
+	///
+	/// See [`slice::chunks_mut`] for more information.
+	pub fn bchunks_mut(
+		&mut self,
+		chunk_size: usize,
+	) -> impl DoubleEndedIterator<Item = &mut Self> + ExactSizeIterator {
+		self.s
+			.chunks_mut(chunk_size)
+			.map(|s| unsafe { Self::from_slice_mut_unchecked(s) })
+	}
+	/// Returns an iterator over `chunk_size` elements of the slice at a time, starting at the end of the slice.
+	///
+	/// See [`slice::rchunks`] for more information.
+	pub fn brchunks(
+		&self,
+		chunk_size: usize,
+	) -> impl DoubleEndedIterator<Item = &Self> + ExactSizeIterator {
+		self.s
+			.rchunks(chunk_size)
+			.map(|s| unsafe { Self::from_slice_unchecked(s) })
+	}
+	/// Returns an iterator over `chunk_size` elements of the slice at a time, starting at the end of the slice.
+	///
+	/// See [`slice::rchunks_mut`] for more information.
+	pub fn brchunks_mut(
+		&mut self,
+		chunk_size: usize,
+	) -> impl DoubleEndedIterator<Item = &mut Self> + ExactSizeIterator {
+		self.s
+			.rchunks_mut(chunk_size)
+			.map(|s| unsafe { Self::from_slice_mut_unchecked(s) })
+	}
+	/// Calls `f` on each chunk of `N` elements of the slice, the last one possibly being shorter.
+	///
+	/// # Panics
+	///
+	/// Panics if `N` is 0.
+	///
+	/// ```
+	/// # use maxlen::{bslice, BSlice};
+	/// let s: &BSlice<u8, 8> = bslice![1, 2, 3, 4, 5];
+	/// let mut sums = Vec::new();
+	/// s.for_each_chunk::<2, _>(|c| sums.push(c.iter().sum::<u8>()));
+	/// assert_eq!(sums, [3, 7, 5]);
+	/// ```
+	pub fn for_each_chunk<const N: usize, F: FnMut(&BSlice<T, N>)>(&self, mut f: F) {
+		assert!(N != 0, "chunk size must be non-zero");
+
+		for chunk in self.s.chunks(N) {
+			// a chunk never has more than `N` elements
+			f(unsafe { BSlice::from_slice_unchecked(chunk) });
+		}
+	}
+}
+
+impl<const MAX: usize> BSlice<u8, MAX> {
+	/// Concatenates byte slices into a new [`BVec`], performing a runtime check.
+	///
+	/// The total length is checked against `OUT` before anything is allocated,
+	/// and the result is allocated exactly once.
+	///
+	/// ```
+	/// # use maxlen::{bslice, BSlice};
+	/// let header: &BSlice<u8, 4> = bslice![0xCA, 0xFE];
+	/// let body: &BSlice<u8, 4> = bslice![1, 2, 3];
+	/// let frame = BSlice::concat_into_bvec::<8>(&[header, body]).unwrap();
+	/// assert_eq!(frame, [0xCA, 0xFE, 1, 2, 3]);
+	/// assert!(BSlice::concat_into_bvec::<4>(&[header, body]).is_err());
+	/// ```
+	pub fn concat_into_bvec<const OUT: usize>(
+		parts: &[&BSlice<u8, MAX>],
+	) -> Result<BVec<u8, OUT>, LengthExceeded> {
+		let length = parts.iter().map(|p| p.len()).sum::<usize>();
+		if length > OUT {
+			return Err(LengthExceeded {
+				length,
+				maximum: OUT,
+				encoding: LengthExceeded::ELEMENTS,
+			});
+		}
+
+		let mut s = Vec::with_capacity(length);
+		for part in parts {
+			s.extend_from_slice(part);
+		}
+
+		Ok(unsafe { BVec::from_vec_unchecked(s) })
+	}
 }
 
 // Trait implementations relating BSlice and BVec
@@ -143,6 +521,11 @@ impl<T: Clone, const MAX: usize> ToOwned for BSlice<T, MAX> {
 		unsafe { BVec::from_vec_unchecked(self.to_vec()) }
 	}
 }
+impl<T, const MAX: usize> From<Box<BSlice<T, MAX>>> for BVec<T, MAX> {
+	fn from(value: Box<BSlice<T, MAX>>) -> Self {
+		value.into_bvec()
+	}
+}
 impl<T: Clone, const MAX: usize> From<&BSlice<T, MAX>> for BVec<T, MAX> {
 	fn from(value: &BSlice<T, MAX>) -> Self {
 		value.to_owned()
@@ -192,6 +575,20 @@ impl<'a, T, const MAX: usize> TryFrom<&'a mut [T]> for &'a mut BSlice<T, MAX> {
 		BSlice::from_slice_mut(value)
 	}
 }
+impl<'a, T, const MAX: usize, const N: usize> TryFrom<&'a [T; N]> for &'a BSlice<T, MAX> {
+	type Error = LengthExceeded;
+
+	fn try_from(value: &'a [T; N]) -> Result<Self, Self::Error> {
+		BSlice::from_slice(value)
+	}
+}
+impl<'a, T, const MAX: usize, const N: usize> TryFrom<&'a mut [T; N]> for &'a mut BSlice<T, MAX> {
+	type Error = LengthExceeded;
+
+	fn try_from(value: &'a mut [T; N]) -> Result<Self, Self::Error> {
+		BSlice::from_slice_mut(value)
+	}
+}
 impl<T, const MAX: usize> Deref for BSlice<T, MAX> {
 	type Target = [T];
 
@@ -234,6 +631,7 @@ impl<T, const MAX: usize> BorrowMut<[T]> for BSlice<T, MAX> {
 		self
 	}
 }
+#[cfg(feature = "std")]
 impl<const MAX: usize> BufRead for &BSlice<u8, MAX> {
 	fn fill_buf(&mut self) -> std::io::Result<&[u8]> {
 		convert_mut_ref(self).fill_buf()
@@ -398,7 +796,7 @@ impl<'a, T, const MAX: usize> IntoIterator for &'a BSlice<T, MAX> {
 	type IntoIter = <&'a [T] as IntoIterator>::IntoIter;
 
 	fn into_iter(self) -> Self::IntoIter {
-		(&**self).into_iter()
+		self.iter()
 	}
 }
 impl<'a, T, const MAX: usize> IntoIterator for &'a mut BSlice<T, MAX> {
@@ -406,10 +804,10 @@ impl<'a, T, const MAX: usize> IntoIterator for &'a mut BSlice<T, MAX> {
 	type IntoIter = <&'a mut [T] as IntoIterator>::IntoIter;
 
 	fn into_iter(self) -> Self::IntoIter {
-		(&mut **self).into_iter()
+		self.iter_mut()
 	}
 }
-impl<'a, 'b, T, const MAX: usize> IntoIterator for &'b &'a BSlice<T, MAX> {
+impl<'b, T, const MAX: usize> IntoIterator for &'b &BSlice<T, MAX> {
 	type Item = &'b T;
 	type IntoIter = <&'b [T] as IntoIterator>::IntoIter;
 
@@ -663,39 +1061,59 @@ where
 		self.eq(&&***other)
 	}
 }
+impl<T, U, const MAX: usize> PartialEq<VecDeque<U>> for BSlice<T, MAX>
+where
+	T: PartialEq<U>,
+{
+	fn eq(&self, other: &VecDeque<U>) -> bool {
+		self.len() == other.len() && self.iter().zip(other).all(|(a, b)| a == b)
+	}
+}
 impl<T: Eq, const MAX: usize> Eq for BSlice<T, MAX> {}
 impl<T: PartialOrd, const MAX1: usize, const MAX2: usize> PartialOrd<BSlice<T, MAX2>>
 	for BSlice<T, MAX1>
 {
-	fn partial_cmp(&self, other: &BSlice<T, MAX2>) -> Option<std::cmp::Ordering> {
+	fn partial_cmp(&self, other: &BSlice<T, MAX2>) -> Option<core::cmp::Ordering> {
 		(**self).partial_cmp(&**other)
 	}
 }
 impl<T: PartialOrd, const MAX: usize> PartialOrd<BSlice<T, MAX>> for [T] {
-	fn partial_cmp(&self, other: &BSlice<T, MAX>) -> Option<std::cmp::Ordering> {
+	fn partial_cmp(&self, other: &BSlice<T, MAX>) -> Option<core::cmp::Ordering> {
 		self.partial_cmp(&**other)
 	}
 }
 impl<T: PartialOrd, const MAX: usize> PartialOrd<&BSlice<T, MAX>> for [T] {
-	fn partial_cmp(&self, other: &&BSlice<T, MAX>) -> Option<std::cmp::Ordering> {
+	fn partial_cmp(&self, other: &&BSlice<T, MAX>) -> Option<core::cmp::Ordering> {
 		self.partial_cmp(&***other)
 	}
 }
 impl<T: PartialOrd, const MAX: usize> PartialOrd<&mut BSlice<T, MAX>> for [T] {
-	fn partial_cmp(&self, other: &&mut BSlice<T, MAX>) -> Option<std::cmp::Ordering> {
+	fn partial_cmp(&self, other: &&mut BSlice<T, MAX>) -> Option<core::cmp::Ordering> {
 		self.partial_cmp(&***other)
 	}
 }
+impl<T: PartialOrd, const MAX: usize> PartialOrd<VecDeque<T>> for BSlice<T, MAX> {
+	fn partial_cmp(&self, other: &VecDeque<T>) -> Option<core::cmp::Ordering> {
+		self.iter().partial_cmp(other)
+	}
+}
+impl<T: PartialOrd, const MAX: usize> PartialOrd<BSlice<T, MAX>> for VecDeque<T> {
+	fn partial_cmp(&self, other: &BSlice<T, MAX>) -> Option<core::cmp::Ordering> {
+		self.iter().partial_cmp(&**other)
+	}
+}
 impl<T: Ord, const MAX: usize> Ord for BSlice<T, MAX> {
-	fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+	fn cmp(&self, other: &Self) -> core::cmp::Ordering {
 		(**self).cmp(&**other)
 	}
 }
+#[cfg(feature = "std")]
 impl<const MAX: usize> Read for &BSlice<u8, MAX> {
 	fn read(&mut self, buf: &mut [u8]) -> std::io::Result<usize> {
 		convert_mut_ref(self).read(buf)
 	}
 }
+#[cfg(feature = "std")]
 impl<const MAX: usize> Write for &mut BSlice<u8, MAX> {
 	fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
 		convert_mut_mut(self).write(buf)
@@ -705,9 +1123,11 @@ impl<const MAX: usize> Write for &mut BSlice<u8, MAX> {
 	}
 }
 
+#[cfg(feature = "std")]
 fn convert_mut_ref<'a, 'b, T, const MAX: usize>(v: &'a mut &'b BSlice<T, MAX>) -> &'a mut &'b [T] {
 	unsafe { transmute(v) }
 }
+#[cfg(feature = "std")]
 fn convert_mut_mut<'a, 'b, T, const MAX: usize>(
 	v: &'a mut &'b mut BSlice<T, MAX>,
 ) -> &'a mut &'b mut [T] {
@@ -719,7 +1139,7 @@ mod serde_impls {
 	use super::*;
 	use serde::{Deserialize, Serialize, de::Visitor, ser::SerializeSeq};
 
-	impl<'a, T: Serialize, const MAX: usize> Serialize for &'a BSlice<T, MAX> {
+	impl<T: Serialize, const MAX: usize> Serialize for &BSlice<T, MAX> {
 		fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
 		where
 			S: serde::Serializer,
@@ -737,7 +1157,7 @@ mod serde_impls {
 	impl<'de, const MAX: usize> Visitor<'de> for BSLiceVisitor<MAX> {
 		type Value = &'de BSlice<u8, MAX>;
 
-		fn expecting(&self, formatter: &mut std::fmt::Formatter) -> std::fmt::Result {
+		fn expecting(&self, formatter: &mut core::fmt::Formatter) -> core::fmt::Result {
 			formatter.write_str("a byte slice")
 		}
 		fn visit_borrowed_bytes<E>(self, v: &'de [u8]) -> Result<Self::Value, E>
@@ -748,7 +1168,7 @@ mod serde_impls {
 				Ok(b) => Ok(b),
 				Err(_e) => Err(serde::de::Error::invalid_length(
 					v.len(),
-					&format!("{MAX}").as_str(),
+					&alloc::format!("{MAX}").as_str(),
 				)),
 			}
 		}
@@ -763,9 +1183,124 @@ mod serde_impls {
 	}
 }
 
+#[cfg(feature = "rkyv")]
+mod rkyv_impls {
+	use super::*;
+	use core::alloc::{Layout, LayoutError};
+	use rkyv::{
+		Archive, ArchiveUnsized, ArchivedMetadata, DeserializeUnsized, Portable, Serialize,
+		SerializeUnsized,
+		bytecheck::CheckBytes,
+		ptr_meta::{self, Pointee},
+		rancor::{Fallible, Source},
+		ser::{Allocator, Writer},
+		traits::{ArchivePointee, LayoutRaw},
+	};
+
+	// SAFETY: `BSlice` is `repr(transparent)` over a slice, so it has the same metadata.
+	unsafe impl<T, const MAX: usize> Pointee for BSlice<T, MAX> {
+		type Metadata = usize;
+	}
+
+	impl<T, const MAX: usize> LayoutRaw for BSlice<T, MAX> {
+		fn layout_raw(metadata: usize) -> Result<Layout, LayoutError> {
+			<[T]>::layout_raw(metadata)
+		}
+	}
+
+	// SAFETY: `BSlice` is `repr(transparent)` over a slice of portable types.
+	unsafe impl<T: Portable, const MAX: usize> Portable for BSlice<T, MAX> {}
+
+	// SAFETY: the length is checked against `MAX` before the inner slice (and its contents) is checked.
+	unsafe impl<T, C, const MAX: usize> CheckBytes<C> for BSlice<T, MAX>
+	where
+		[T]: CheckBytes<C>,
+		C: Fallible + ?Sized,
+		C::Error: Source,
+	{
+		unsafe fn check_bytes(value: *const Self, context: &mut C) -> Result<(), C::Error> {
+			let length = ptr_meta::metadata(value);
+			if length > MAX {
+				return Err(C::Error::new(LengthExceeded {
+					length,
+					maximum: MAX,
+					encoding: LengthExceeded::ELEMENTS,
+				}));
+			}
+
+			unsafe { <[T]>::check_bytes(value as *const [T], context) }
+		}
+	}
+
+	impl<T, const MAX: usize> ArchivePointee for BSlice<T, MAX> {
+		type ArchivedMetadata = <[T] as ArchivePointee>::ArchivedMetadata;
+
+		fn pointer_metadata(archived: &Self::ArchivedMetadata) -> usize {
+			<[T]>::pointer_metadata(archived)
+		}
+	}
+
+	impl<T: Archive, const MAX: usize> ArchiveUnsized for BSlice<T, MAX> {
+		type Archived = BSlice<T::Archived, MAX>;
+
+		fn archived_metadata(&self) -> ArchivedMetadata<Self> {
+			self.s.archived_metadata()
+		}
+	}
+	impl<T: Serialize<S>, S: Fallible + Allocator + Writer + ?Sized, const MAX: usize>
+		SerializeUnsized<S> for BSlice<T, MAX>
+	{
+		fn serialize_unsized(&self, serializer: &mut S) -> Result<usize, S::Error> {
+			self.s.serialize_unsized(serializer)
+		}
+	}
+	impl<T: Archive, D: Fallible + ?Sized, const MAX: usize> DeserializeUnsized<BSlice<T, MAX>, D>
+		for BSlice<T::Archived, MAX>
+	where
+		[T::Archived]: DeserializeUnsized<[T], D>,
+		D::Error: Source,
+	{
+		unsafe fn deserialize_unsized(
+			&self,
+			deserializer: &mut D,
+			out: *mut BSlice<T, MAX>,
+		) -> Result<(), D::Error> {
+			// checked again, since the archive may have been accessed without validation
+			if self.s.len() > MAX {
+				return Err(D::Error::new(LengthExceeded {
+					length: self.s.len(),
+					maximum: MAX,
+					encoding: LengthExceeded::ELEMENTS,
+				}));
+			}
+
+			unsafe { self.s.deserialize_unsized(deserializer, out as *mut [T]) }
+		}
+		fn deserialize_metadata(&self) -> usize {
+			self.s.deserialize_metadata()
+		}
+	}
+}
+
+#[cfg(feature = "speedy")]
+mod speedy_impls {
+	use super::*;
+	use speedy::{Context, Writable, Writer};
+
+	impl<C: Context, T: Writable<C>, const MAX: usize> Writable<C> for BSlice<T, MAX> {
+		fn write_to<W: ?Sized + Writer<C>>(&self, writer: &mut W) -> Result<(), C::Error> {
+			self.s.write_to(writer)
+		}
+		fn bytes_needed(&self) -> Result<usize, C::Error> {
+			Writable::<C>::bytes_needed(&self.s)
+		}
+	}
+}
+
 #[cfg(test)]
 mod tests {
 	use crate::*;
+	use alloc::vec::Vec;
 
 	#[test]
 	fn test_bslice_macro() {
@@ -775,4 +1310,341 @@ mod tests {
 		let _: &BSlice<_, 255> = bslice![0, 1, 2, 3, 4];
 		// let _: &BSlice<_, 3> = bslice![0, 1, 2, 3, 4]; // should fail
 	}
+
+	#[test]
+	fn test_try_from_array() {
+		fn first<const N: usize>(arr: &[u8; N]) -> Option<u8> {
+			let s: &BSlice<u8, 4> = arr.try_into().ok()?;
+			s.first().copied()
+		}
+
+		assert_eq!(first(&[1, 2, 3]), Some(1));
+		assert_eq!(first(&[5, 6, 7, 8]), Some(5));
+		assert_eq!(first(&[1, 2, 3, 4, 5]), None);
+		assert_eq!(first(&[]), None);
+
+		let mut arr = [1, 2, 3];
+		let s: &mut BSlice<u8, 3> = (&mut arr).try_into().unwrap();
+		s[0] = 9;
+		assert_eq!(arr, [9, 2, 3]);
+		let err = <&mut BSlice<u8, 2>>::try_from(&mut arr).unwrap_err();
+		assert_eq!(err.length, 3);
+	}
+
+	#[cfg(feature = "std")]
+	#[test]
+	fn test_buf_read() {
+		use alloc::string::String;
+		use std::io::BufRead;
+
+		let text = b"first\nsecond\n\nlast";
+		let s: &BSlice<u8, 32> = BSlice::from_slice(text).unwrap();
+
+		let mut r = s;
+		let mut buf = Vec::new();
+		assert_eq!(r.read_until(b'\n', &mut buf).unwrap(), 6);
+		assert_eq!(buf, b"first\n");
+		assert_eq!(r, b"second\n\nlast");
+		buf.clear();
+		assert_eq!(r.read_until(b'\n', &mut buf).unwrap(), 7);
+		assert_eq!(buf, b"second\n");
+		buf.clear();
+		assert_eq!(r.read_until(b'\n', &mut buf).unwrap(), 1);
+		assert_eq!(buf, b"\n");
+		buf.clear();
+		// no trailing newline
+		assert_eq!(r.read_until(b'\n', &mut buf).unwrap(), 4);
+		assert_eq!(buf, b"last");
+		assert!(r.is_empty());
+		assert_eq!(r.read_until(b'\n', &mut buf).unwrap(), 0);
+
+		let mut line = String::new();
+		let mut r = s;
+		r.read_line(&mut line).unwrap();
+		assert_eq!(line, "first\n");
+
+		let lines = s.lines().collect::<Result<Vec<_>, _>>().unwrap();
+		assert_eq!(lines, ["first", "second", "", "last"]);
+	}
+
+	#[test]
+	fn test_to_bvec() {
+		let s: &BSlice<u8, 8> = bslice![1, 2, 3];
+		assert_eq!(s.to_bvec(), [1, 2, 3]);
+
+		let b = s.to_bvec().into_boxed_slice();
+		let ptr = b.as_ptr();
+		let v = b.into_bvec();
+		assert_eq!(v.as_ptr(), ptr);
+		assert_eq!(v.capacity(), 3);
+		assert_eq!(v, [1, 2, 3]);
+
+		let b = v.into_boxed_slice();
+		let ptr = b.as_ptr();
+		assert_eq!(BVec::from(b).as_ptr(), ptr);
+	}
+
+	#[test]
+	fn test_concat_into_bvec() {
+		let a: &BSlice<u8, 4> = bslice![1, 2, 3, 4];
+		let b: &BSlice<u8, 4> = bslice![5];
+		let c: &BSlice<u8, 4> = Default::default();
+
+		let v = BSlice::concat_into_bvec::<9>(&[a, b, c, a]).unwrap();
+		assert_eq!(v, [1, 2, 3, 4, 5, 1, 2, 3, 4]);
+		assert_eq!(v.capacity(), 9);
+
+		let err = BSlice::concat_into_bvec::<8>(&[a, b, c, a]).unwrap_err();
+		assert_eq!(err.length, 9);
+		assert_eq!(err.maximum, 8);
+
+		assert!(BSlice::concat_into_bvec::<0>(&[c, c]).unwrap().is_empty());
+		assert!(BSlice::concat_into_bvec::<0>(&[a][..0]).unwrap().is_empty());
+	}
+
+	#[test]
+	fn test_starts_ends_with() {
+		let s: &BSlice<u8, 16> = bslice![1, 2, 3, 4];
+		let prefix: &BSlice<u8, 2> = bslice![1, 2];
+		let suffix: BVec<u8, 3> = bvec![2, 3, 4];
+		let empty: &BSlice<u8, 0> = Default::default();
+
+		assert!(s.starts_with(prefix));
+		assert!(!s.starts_with(&*suffix));
+		assert!(s.ends_with(&suffix));
+		assert!(!s.ends_with(prefix));
+		assert!(s.starts_with(empty) && s.ends_with(empty));
+		assert!(s.starts_with(s) && s.ends_with(s));
+		assert!(!prefix.starts_with(s));
+
+		// plain slices, arrays and vectors work too
+		assert!(s.starts_with(&[1]));
+		assert!(s.ends_with(&s[3..]));
+		assert!(s.ends_with(&alloc::vec![3, 4]));
+
+		assert!(s.contains(&3));
+		assert!(!s.contains(&5));
+		assert!(!empty.contains(&0));
+	}
+
+	#[test]
+	fn test_get_disjoint_mut() {
+		use core::slice::GetDisjointMutError;
+
+		let mut v: BVec<u8, 8> = bvec![1, 2, 3, 4];
+		let [a, b] = v.get_disjoint_mut([0, 3]).unwrap();
+		core::mem::swap(a, b);
+		assert_eq!(v, [4, 2, 3, 1]);
+
+		let [a, b, c] = v.get_disjoint_mut([2, 1, 0]).unwrap();
+		*a += 10;
+		*b += 20;
+		*c += 30;
+		assert_eq!(v, [34, 22, 13, 1]);
+
+		assert_eq!(
+			v.get_disjoint_mut([1, 1]).unwrap_err(),
+			GetDisjointMutError::OverlappingIndices
+		);
+		assert_eq!(
+			v.get_disjoint_mut([0, 4]).unwrap_err(),
+			GetDisjointMutError::IndexOutOfBounds
+		);
+		assert!(v.get_disjoint_mut([]).is_ok());
+	}
+
+	#[test]
+	fn test_fill() {
+		let mut arr = [1, 2, 3, 4];
+		let s = BSlice::<u8, 4>::from_slice_mut(&mut arr).unwrap();
+		s.fill(9);
+		assert_eq!(*s, [9; 4]);
+
+		let mut next = 0;
+		s.fill_with(|| {
+			next += 1;
+			next
+		});
+		assert_eq!(*s, [1, 2, 3, 4]);
+
+		let mut v: BVec<u8, 8> = bvec![1, 2, 3];
+		v.fill(0);
+		assert_eq!(v, [0; 3]);
+	}
+
+	#[test]
+	fn test_take_skip() {
+		let s: &BSlice<u8, 8> = bslice![1, 2, 3];
+		assert_eq!(s.take(2), [1, 2]);
+		assert_eq!(s.skip(2), [3]);
+		assert!(s.take(0).is_empty());
+		assert_eq!(s.skip(0), s);
+		assert_eq!(s.take(10), s);
+		assert!(s.skip(10).is_empty());
+	}
+
+	#[test]
+	fn test_split_at_checked() {
+		let s: &BSlice<u8, 8> = bslice![1, 2, 3];
+		assert_eq!(s.split_at_checked(0), Some((bslice![], s)));
+		assert_eq!(s.split_at_checked(1), Some((bslice![1], bslice![2, 3])));
+		assert_eq!(s.split_at_checked(3), Some((s, bslice![])));
+		assert_eq!(s.split_at_checked(4), None);
+	}
+
+	#[test]
+	fn test_split_array_ref() {
+		let s: &BSlice<u8, 8> = bslice![1, 2, 3, 4];
+		assert_eq!(s.split_array_ref::<4>(), (&[1, 2, 3, 4], bslice![]));
+		assert_eq!(s.split_array_ref::<1>(), (&[1], bslice![2, 3, 4]));
+		assert_eq!(s.split_array_ref::<0>(), (&[], s));
+		assert_eq!(s.rsplit_array_ref::<4>(), (bslice![], &[1, 2, 3, 4]));
+		assert_eq!(s.rsplit_array_ref::<1>(), (bslice![1, 2, 3], &[4]));
+	}
+
+	#[test]
+	#[should_panic = "shorter than the array"]
+	fn test_split_array_ref_short() {
+		let s: &BSlice<u8, 8> = bslice![1, 2, 3];
+		s.split_array_ref::<4>();
+	}
+
+	#[test]
+	fn test_align_to() {
+		#[repr(C, align(4))]
+		struct Aligned([u8; 12]);
+
+		let buf = Aligned([1, 0, 0, 0, 2, 0, 0, 0, 3, 0, 0, 0]);
+		let s = BSlice::<u8, 12>::from_slice(&buf.0).unwrap();
+
+		let (prefix, middle, suffix) = unsafe { s.align_to::<u32>() };
+		assert!(prefix.is_empty());
+		assert!(suffix.is_empty());
+		let values: Vec<u32> = middle.iter().map(|&x| u32::from_le(x)).collect();
+		assert_eq!(values, [1, 2, 3]);
+
+		let (prefix, middle, suffix) = unsafe { s.skip(1).take(9).align_to::<u32>() };
+		assert_eq!(*prefix, [0, 0, 0]);
+		assert_eq!(middle.len(), 1);
+		assert_eq!(u32::from_le(middle[0]), 2);
+		assert_eq!(*suffix, [3, 0]);
+	}
+
+	#[test]
+	fn test_partition() {
+		let s: &BSlice<u32, 8> = bslice![1, 2, 2, 4, 7, 9];
+		assert_eq!(s.partition_point(|&x| x < 2), 1);
+		assert_eq!(s.partition_point(|&x| x <= 2), 3);
+		assert_eq!(s.partition_point(|_| true), 6);
+		assert_eq!(s.partition_point(|_| false), 0);
+
+		let (l, r) = s.partition(|&x| x < 5);
+		assert_eq!(l, [1, 2, 2, 4]);
+		assert_eq!(r, [7, 9]);
+		let (l, r) = s.partition(|_| true);
+		assert_eq!((l.len(), r.len()), (6, 0));
+		let (l, r) = s.partition(|&x| x < 1);
+		assert_eq!((l.len(), r.len()), (0, 6));
+	}
+
+	#[test]
+	fn test_split() {
+		let s: &BSlice<u8, 8> = bslice![1, 0, 2, 3, 0, 4];
+		fn collect<'a>(i: impl Iterator<Item = &'a BSlice<u8, 8>>) -> Vec<&'a [u8]> {
+			i.map(|s| &**s).collect()
+		}
+
+		assert_eq!(collect(s.split(|&b| b == 0)), [&[1][..], &[2, 3], &[4]]);
+		assert_eq!(collect(s.rsplit(|&b| b == 0)), [&[4][..], &[2, 3], &[1]]);
+		assert!(collect(s.splitn(0, |&b| b == 0)).is_empty());
+		assert_eq!(collect(s.splitn(1, |&b| b == 0)), [&[1, 0, 2, 3, 0, 4][..]]);
+		assert_eq!(collect(s.splitn(2, |&b| b == 0)), [&[1][..], &[2, 3, 0, 4]]);
+		assert_eq!(
+			collect(s.splitn(10, |&b| b == 0)),
+			[&[1][..], &[2, 3], &[4]]
+		);
+		assert!(collect(s.rsplitn(0, |&b| b == 0)).is_empty());
+		assert_eq!(
+			collect(s.rsplitn(1, |&b| b == 0)),
+			[&[1, 0, 2, 3, 0, 4][..]]
+		);
+		assert_eq!(
+			collect(s.rsplitn(2, |&b| b == 0)),
+			[&[4][..], &[1, 0, 2, 3]]
+		);
+		assert_eq!(
+			collect(s.rsplitn(10, |&b| b == 0)),
+			[&[4][..], &[2, 3], &[1]]
+		);
+	}
+
+	#[test]
+	fn test_as_chunks() {
+		let v: BVec<u8, 8> = bvec![1, 2, 3, 4, 5, 6];
+
+		let (chunks, remainder) = v.as_chunks::<3>();
+		assert_eq!(chunks, [[1, 2, 3], [4, 5, 6]]);
+		assert!(remainder.is_empty());
+
+		let (chunks, remainder) = v.as_chunks::<4>();
+		assert_eq!(chunks, [[1, 2, 3, 4]]);
+		assert_eq!(remainder, [5, 6]);
+
+		let (chunks, remainder) = v.as_chunks::<7>();
+		assert!(chunks.is_empty());
+		assert_eq!(*remainder, v);
+	}
+
+	#[test]
+	fn test_for_each_chunk() {
+		let s: &BSlice<u8, 16> = bslice![0; 11];
+		let mut total = 0;
+		let mut count = 0;
+		s.for_each_chunk::<4, _>(|c| {
+			assert!(c.len() <= 4);
+			total += c.len();
+			count += 1;
+		});
+		assert_eq!(total, 11);
+		assert_eq!(count, 3);
+
+		let s: &BSlice<u8, 16> = bslice![];
+		s.for_each_chunk::<4, _>(|_| panic!());
+	}
+
+	#[test]
+	fn test_chunks() {
+		let mut v: BVec<u8, 8> = bvec![1, 2, 3, 4, 5];
+
+		let chunks: Vec<&[u8]> = v.bchunks(2).map(|c| &**c).collect();
+		assert_eq!(chunks, [&[1, 2][..], &[3, 4], &[5]]);
+		let chunks: Vec<&[u8]> = v.brchunks(2).map(|c| &**c).collect();
+		assert_eq!(chunks, [&[4, 5][..], &[2, 3], &[1]]);
+
+		for (i, c) in v.bchunks_mut(2).enumerate() {
+			c[0] = i as u8 * 10;
+		}
+		assert_eq!(v, [0, 2, 10, 4, 20]);
+		for (i, c) in v.brchunks_mut(2).enumerate() {
+			c[0] = i as u8 + 100;
+		}
+		assert_eq!(v, [102, 101, 10, 100, 20]);
+	}
+
+	#[test]
+	fn test_hash_boxed() {
+		use alloc::boxed::Box;
+		use core::hash::BuildHasher;
+		use std::{collections::HashMap, hash::RandomState};
+
+		let state = RandomState::new();
+		let s: &BSlice<u8, 8> = bslice![1, 2, 3];
+		let boxed: Box<BSlice<u8, 8>> = s.into();
+		assert_eq!(state.hash_one(&boxed), state.hash_one(s));
+		assert_eq!(state.hash_one(s), state.hash_one(&[1u8, 2, 3][..]));
+
+		let mut map = HashMap::new();
+		map.insert(boxed, 1);
+		assert_eq!(map.get(s), Some(&1));
+	}
 }
@@ -2,32 +2,64 @@ use crate::{
 	BSlice, BString, LengthExceeded, const_checks,
 	encoding::{Encoding, Utf8},
 };
-use std::{
-	borrow::Cow,
-	ffi::{OsStr, OsString},
+use alloc::{
+	borrow::{Cow, ToOwned},
+	boxed::Box,
+	rc::Rc,
+	string::String,
+	sync::Arc,
+};
+use core::{
+	borrow::Borrow,
 	fmt::Display,
+	hash::{Hash, Hasher},
+	iter::FusedIterator,
 	marker::PhantomData,
-	net::ToSocketAddrs,
 	ops::{
 		Add, AddAssign, Bound, Deref, Index, Range, RangeFrom, RangeFull, RangeInclusive, RangeTo,
 		RangeToInclusive,
 	},
+	str::FromStr,
+};
+#[cfg(feature = "std")]
+use std::{
+	ffi::{OsStr, OsString},
+	net::ToSocketAddrs,
 	path::Path,
-	rc::Rc,
-	sync::Arc,
 };
 
 /// Bounded [`str`].
 ///
 /// Guaranteed to not be longer than `MAX` bytes in the [`E`][crate::encoding::Encoding] encoding representation.
-#[derive(Debug, Hash)]
+///
+/// Only the operations that check or measure the length in the encoding require
+/// `E: Encoding`. Reading, comparing and formatting work for any `E`.
+///
+/// The encoding only affects how the length is counted, not the identity of the string:
+/// strings with the same text compare equal whatever their `MAX` and `E`.
+///
+/// A `Box<BStr>` converts into `Arc<BStr>` and `Rc<BStr>` through std's blanket `From<Box<T>>`
+/// impls. These copy the contents into a new allocation, as the reference counts are stored
+/// inline with the data.
+///
+/// ```
+/// # use std::sync::Arc;
+/// # use maxlen::{bstr, BStr};
+/// let b: Box<BStr<16>> = bstr!(16, "shared").into();
+/// let arc: Arc<BStr<16>> = b.into();
+/// assert_eq!(&*arc, "shared");
+/// ```
+#[derive(Debug)]
 #[repr(transparent)]
 pub struct BStr<const MAX: usize, E = Utf8> {
 	phantom: PhantomData<fn(E) -> E>,
 	s: str,
 }
 
-impl<E: Encoding, const MAX: usize> BStr<MAX, E> {
+/// The `Pattern` trait can't be named on stable, so the methods that search for a pattern
+/// take a `&str`, with [`bsplit_inclusive`](Self::bsplit_inclusive) also taking a `char`
+/// predicate. The [`str`] methods accepting any pattern are still reachable through deref.
+impl<E, const MAX: usize> BStr<MAX, E> {
 	/// Creates a `&BStr<MAX, E>` from a `&str` without any checks.
 	///
 	/// # Safety
@@ -35,7 +67,7 @@ impl<E: Encoding, const MAX: usize> BStr<MAX, E> {
 	/// The caller is responsible for making sure that the string is definitely
 	/// not longer than `MAX` bytes in the given encoding.
 	pub const unsafe fn from_str_unchecked(s: &str) -> &Self {
-		unsafe { std::mem::transmute(s) }
+		unsafe { core::mem::transmute(s) }
 	}
 	/// Creates a `&mut BStr<MAX, E>` from a `&mut str` without any checks.
 	///
@@ -44,71 +76,120 @@ impl<E: Encoding, const MAX: usize> BStr<MAX, E> {
 	/// The caller is responsible for making sure that the string is definitely
 	/// not longer than `MAX` bytes in the given encoding.
 	pub const unsafe fn from_str_mut_unchecked(s: &mut str) -> &mut Self {
-		unsafe { std::mem::transmute(s) }
-	}
-	/// Creates a `&BStr<MAX, E>` from a `&str`, performing a runtime check.
-	pub fn from_str(s: &str) -> Result<&Self, LengthExceeded> {
-		let length = E::length(s);
-		if length > MAX {
-			return Err(LengthExceeded {
-				length,
-				maximum: MAX,
-			});
-		}
-
-		Ok(unsafe { Self::from_str_unchecked(s) })
-	}
-	/// Creates a `&mut BStr<MAX, E>` from a `&mut str`, performing a runtime check.
-	pub fn from_str_mut(s: &mut str) -> Result<&mut Self, LengthExceeded> {
-		let length = E::length(s);
-		if length > MAX {
-			return Err(LengthExceeded {
-				length,
-				maximum: MAX,
-			});
-		}
-
-		Ok(unsafe { Self::from_str_mut_unchecked(s) })
+		unsafe { core::mem::transmute(s) }
 	}
 	/// Creates an empty `&BStr`.
 	pub fn new<'a>() -> &'a Self {
 		Default::default()
 	}
+	/// Returns the number of [`char`]s in the string.
+	///
+	/// This is not the length bounded by `MAX`, see [`encoded_len`](BStr::encoded_len) for that.
+	pub fn char_len(&self) -> usize {
+		self.s.chars().count()
+	}
+	/// Returns an iterator over the [`char`]s of the string and their byte positions.
+	///
+	/// Since the UTF-8 length never exceeds the encoded length, every position is below `MAX`.
+	///
+	/// See [`str::char_indices`] for more information.
+	///
+	/// ```
+	/// # use maxlen::{bstr, BStr};
+	/// let s: &BStr<8> = bstr!(8, "aą🦀");
+	/// let indices: Vec<(usize, char)> = s.char_indices().collect();
+	/// assert_eq!(indices, [(0, 'a'), (1, 'ą'), (3, '🦀')]);
+	/// ```
+	pub fn char_indices(&self) -> core::str::CharIndices<'_> {
+		self.s.char_indices()
+	}
+	/// Returns an iterator over the UTF-8 bytes of the string.
+	///
+	/// There are never more than `MAX` of them, see [`as_bslice`](Self::as_bslice).
+	///
+	/// See [`str::bytes`] for more information.
+	pub fn bytes(&self) -> core::str::Bytes<'_> {
+		self.s.bytes()
+	}
+	/// Returns the number of extended grapheme clusters in the string.
+	///
+	/// This is usually what a user perceives as the number of characters.
+	#[cfg(feature = "unicode")]
+	pub fn grapheme_len(&self) -> usize {
+		unicode_segmentation::UnicodeSegmentation::graphemes(&self.s, true).count()
+	}
+	/// Returns the UTF-8 bytes of the string as a bounded byte slice.
+	///
+	/// For encodings other than [`Utf8`] the byte slice may be shorter than
+	/// [`encoded_len`](Self::encoded_len), but it never exceeds `MAX`.
+	///
+	/// ```
+	/// # use maxlen::{bstr, BSlice, BStr, encoding::Cesu8};
+	/// let s: &BStr<8, Cesu8> = bstr!(8, Cesu8, "🦀");
+	/// let bytes: &BSlice<u8, 8> = s.as_bslice();
+	/// assert_eq!(bytes.len(), 4);
+	/// assert_eq!(s.encoded_len(), 6);
+	/// ```
+	pub fn as_bslice(&self) -> &BSlice<u8, MAX> {
+		// UTF-8 is never longer than any of the encodings
+		unsafe { BSlice::from_slice_unchecked(self.s.as_bytes()) }
+	}
+	/// Gives the underlying unbounded string slice.
+	///
+	/// Same as dereferencing, but explicit.
+	///
+	/// ```
+	/// # use maxlen::{bstr, BStr, BString};
+	/// fn shout(s: &str) -> String {
+	///     s.to_uppercase()
+	/// }
+	///
+	/// let s: &BStr<8> = bstr!(8, "hey");
+	/// assert_eq!(shout(s.as_str()), "HEY");
+	/// let owned = BString::<8>::from_str("you").unwrap();
+	/// assert_eq!(shout(owned.as_str()), "YOU");
+	/// ```
+	pub const fn as_str(&self) -> &str {
+		&self.s
+	}
 	/// Relaxes the `MAX` bound, converting to a type with a bigger one.
 	///
 	/// This conversion is free and does not involve any checks. It is
 	/// asserted at compile time that the new `MAX` is bigger than before.
 	pub const fn relax_max<const MAX2: usize>(&self) -> &BStr<MAX2, E> {
 		// assert that MAX2 >= MAX at compile time
-		_ = <const_checks::Pair<MAX2, MAX> as const_checks::AssertGe>::VALID;
+		let () = <const_checks::Pair<MAX2, MAX> as const_checks::AssertGe>::VALID;
 
 		unsafe { BStr::from_str_unchecked(&self.s) }
 	}
-	/// Changes the `MAX` bound (and optionally the encoding type).
-	///
-	/// This involves a check whether the new bound is met.
-	pub fn change_max<E2: Encoding, const MAX2: usize>(
-		&self,
-	) -> Result<&BStr<MAX2, E2>, LengthExceeded> {
-		BStr::from_str(self)
-	}
 	/// Relaxes the `MAX` bound, converting to a type with a bigger one.
 	///
 	/// This conversion is free and does not involve any checks. It is
 	/// asserted at compile time that the new `MAX` is bigger than before.
 	pub const fn relax_max_mut<const MAX2: usize>(&mut self) -> &mut BStr<MAX2, E> {
 		// assert that MAX2 >= MAX at compile time
-		_ = <const_checks::Pair<MAX2, MAX> as const_checks::AssertGe>::VALID;
+		let () = <const_checks::Pair<MAX2, MAX> as const_checks::AssertGe>::VALID;
 
 		unsafe { BStr::from_str_mut_unchecked(&mut self.s) }
 	}
-	/// Changes the `MAX` bound (and optionally the encoding type).
+	/// Divides one string slice into two at an index.
 	///
-	/// This involves a check whether the new bound is met.
-	pub fn change_max_mut<E2: Encoding, const MAX2: usize>(
-		&mut self,
-	) -> Result<&mut BStr<MAX2, E2>, LengthExceeded> {
-		BStr::from_str_mut(&mut self.s)
+	/// See [`str::split_at`] for more information.
+	pub fn split_at(&self, mid: usize) -> (&Self, &Self) {
+		let (l, r) = self.s.split_at(mid);
+
+		// Two subslices will always be shorter than the original
+		// and therefore valid for the length constrains
+		unsafe { (Self::from_str_unchecked(l), Self::from_str_unchecked(r)) }
+	}
+	/// Divides one string slice into two at an index, returning `None` if `mid`
+	/// is out of bounds or not on a char boundary.
+	///
+	/// See [`str::split_at_checked`] for more information.
+	pub fn split_at_checked(&self, mid: usize) -> Option<(&Self, &Self)> {
+		let (l, r) = self.s.split_at_checked(mid)?;
+
+		unsafe { Some((Self::from_str_unchecked(l), Self::from_str_unchecked(r))) }
 	}
 	/// Divides one mutable string slice into two at an index.
 	///
@@ -137,6 +218,368 @@ impl<E: Encoding, const MAX: usize> BStr<MAX, E> {
 	pub fn make_ascii_lowercase(&mut self) {
 		self.s.make_ascii_lowercase();
 	}
+	/// Converts the first letter of each whitespace-delimited word to its ASCII
+	/// upper case equivalent in-place.
+	///
+	/// Leading non-letter characters of a word are skipped, including non-ASCII ones such as `«`.
+	/// Non-ASCII letters count as the first letter but are left as is, and the rest of the word
+	/// is not changed.
+	///
+	/// ```
+	/// # use maxlen::BString;
+	/// let mut s = BString::<32>::from_str("hello (big) «wide» wORLD").unwrap();
+	/// s.make_ascii_titlecase();
+	/// assert_eq!(s, "Hello (Big) «Wide» WORLD");
+	/// ```
+	pub fn make_ascii_titlecase(&mut self) {
+		let mut word_start = true;
+		let mut i = 0;
+		while let Some(c) = self.s[i..].chars().next() {
+			let end = i + c.len_utf8();
+			if c.is_whitespace() {
+				word_start = true;
+			} else if word_start && c.is_alphabetic() {
+				self.s[i..end].make_ascii_uppercase();
+				word_start = false;
+			}
+			i = end;
+		}
+	}
+	/// Checks that two strings have the same text, regardless of their bounds and encodings.
+	///
+	/// This is what `==` does too, spelled out for comparisons across encodings.
+	///
+	/// ```
+	/// # use maxlen::{bstr, BStr, encoding::Cesu8};
+	/// let a: &BStr<8> = bstr!(8, "🦀");
+	/// let b: &BStr<6, Cesu8> = bstr!(6, Cesu8, "🦀");
+	/// assert!(a.content_eq(b));
+	/// assert_ne!(a.encoded_len(), b.encoded_len());
+	/// ```
+	pub fn content_eq<E2, const MAX2: usize>(&self, other: &BStr<MAX2, E2>) -> bool {
+		self.s == other.s
+	}
+	/// Checks that two strings are an ASCII case-insensitive match.
+	///
+	/// See [`str::eq_ignore_ascii_case`] for more information.
+	pub fn eq_ignore_ascii_case<E2, const MAX2: usize>(&self, other: &BStr<MAX2, E2>) -> bool {
+		self.s.eq_ignore_ascii_case(&other.s)
+	}
+	/// Returns `true` if the string starts with `prefix`, ignoring ASCII case.
+	///
+	/// Non-ASCII bytes must match exactly.
+	///
+	/// ```
+	/// # use maxlen::{bstr, BStr};
+	/// let s: &BStr<32> = bstr!(32, "Content-Type: text/plain");
+	/// assert!(s.starts_with_ignore_ascii_case("content-type:"));
+	/// assert!(!s.starts_with_ignore_ascii_case("content-length:"));
+	/// ```
+	pub fn starts_with_ignore_ascii_case(&self, prefix: &str) -> bool {
+		self.s
+			.as_bytes()
+			.get(..prefix.len())
+			.is_some_and(|start| start.eq_ignore_ascii_case(prefix.as_bytes()))
+	}
+	/// Returns an iterator over substrings terminated by characters matching `pred`,
+	/// keeping the terminator at the end of each substring.
+	///
+	/// See [`str::split_inclusive`] for more information.
+	///
+	/// ```
+	/// # use maxlen::{bstr, BStr};
+	/// let s: &BStr<16> = bstr!(16, "a\nb\n");
+	/// let lines: Vec<&str> = s.bsplit_inclusive(|c| c == '\n').map(|l| &**l).collect();
+	/// assert_eq!(lines, ["a\n", "b\n"]);
+	/// ```
+	pub fn bsplit_inclusive<F>(&self, pred: F) -> impl DoubleEndedIterator<Item = &Self>
+	where
+		F: FnMut(char) -> bool,
+	{
+		// substrings are never longer than the original
+		self.s
+			.split_inclusive(pred)
+			.map(|s| unsafe { Self::from_str_unchecked(s) })
+	}
+	/// Returns an iterator over substrings terminated by `pat`,
+	/// keeping the terminator at the end of each substring.
+	///
+	/// See [`str::split_inclusive`] for more information.
+	///
+	/// ```
+	/// # use maxlen::{bstr, BStr};
+	/// let s: &BStr<16> = bstr!(16, "a\r\nb\r\n");
+	/// let lines: Vec<&str> = s.bsplit_inclusive_str("\r\n").map(|l| &**l).collect();
+	/// assert_eq!(lines, ["a\r\n", "b\r\n"]);
+	/// ```
+	pub fn bsplit_inclusive_str<'a, 'p>(
+		&'a self,
+		pat: &'p str,
+	) -> impl Iterator<Item = &'a Self> + use<'a, 'p, E, MAX> {
+		// substrings are never longer than the original
+		self.s
+			.split_inclusive(pat)
+			.map(|s| unsafe { Self::from_str_unchecked(s) })
+	}
+	/// Returns an iterator over the disjoint matches of `pat` in the string.
+	///
+	/// See [`str::matches`] for more information.
+	pub fn bmatches<'a, 'p>(
+		&'a self,
+		pat: &'p str,
+	) -> impl Iterator<Item = &'a Self> + use<'a, 'p, E, MAX> {
+		// matches are never longer than the original
+		self.s
+			.matches(pat)
+			.map(|s| unsafe { Self::from_str_unchecked(s) })
+	}
+	/// Returns an iterator over the disjoint matches of `pat` in the string,
+	/// along with the byte index at which each match starts.
+	///
+	/// See [`str::match_indices`] for more information.
+	pub fn bmatch_indices<'a, 'p>(
+		&'a self,
+		pat: &'p str,
+	) -> impl Iterator<Item = (usize, &'a Self)> + use<'a, 'p, E, MAX> {
+		self.s
+			.match_indices(pat)
+			.map(|(i, s)| (i, unsafe { Self::from_str_unchecked(s) }))
+	}
+	/// Parses this string slice into another type.
+	///
+	/// See [`str::parse`] for more information.
+	///
+	/// ```
+	/// # use maxlen::{bstr, BStr};
+	/// let s: &BStr<10> = bstr!(10, "4096");
+	/// assert_eq!(s.parse::<u32>(), Ok(4096));
+	/// ```
+	pub fn parse<T: FromStr>(&self) -> Result<T, T::Err> {
+		self.s.parse()
+	}
+}
+
+impl<E: Encoding, const MAX: usize> BStr<MAX, E> {
+	/// Creates a `&BStr<MAX, E>` from a `&str`, performing a runtime check.
+	#[allow(clippy::should_implement_trait)]
+	pub fn from_str(s: &str) -> Result<&Self, LengthExceeded> {
+		let length = E::length(s);
+		if length > MAX {
+			return Err(LengthExceeded {
+				length,
+				maximum: MAX,
+				encoding: E::NAME,
+			});
+		}
+
+		Ok(unsafe { Self::from_str_unchecked(s) })
+	}
+	/// Creates a `&mut BStr<MAX, E>` from a `&mut str`, performing a runtime check.
+	pub fn from_str_mut(s: &mut str) -> Result<&mut Self, LengthExceeded> {
+		let length = E::length(s);
+		if length > MAX {
+			return Err(LengthExceeded {
+				length,
+				maximum: MAX,
+				encoding: E::NAME,
+			});
+		}
+
+		Ok(unsafe { Self::from_str_mut_unchecked(s) })
+	}
+	/// Returns the length of the string in the `E` encoding.
+	///
+	/// This is the length that is bounded by `MAX`, and is not necessarily the same as [`str::len`].
+	pub fn encoded_len(&self) -> usize {
+		E::length(&self.s)
+	}
+	/// Returns the length of the string in encoding units of `E`.
+	///
+	/// This is an alias of [`encoded_len`](Self::encoded_len).
+	pub fn unit_len(&self) -> usize {
+		self.encoded_len()
+	}
+	/// Maps a position in encoding units of `E` to the corresponding byte index in the string.
+	///
+	/// Returns `None` if `unit` falls in the middle of a character or past the end of the string.
+	///
+	/// ```
+	/// # use maxlen::{bstr, BStr, encoding::Cesu8};
+	/// let s: &BStr<16, Cesu8> = bstr!(16, Cesu8, "a🦀b");
+	/// assert_eq!(s.byte_index_of_unit(1), Some(1));
+	/// assert_eq!(s.byte_index_of_unit(3), None);
+	/// assert_eq!(s.byte_index_of_unit(7), Some(5));
+	/// ```
+	pub fn byte_index_of_unit(&self, unit: usize) -> Option<usize> {
+		let mut units = 0;
+		for (i, c) in self.s.char_indices() {
+			if units >= unit {
+				return (units == unit).then_some(i);
+			}
+			units += E::length(c.encode_utf8(&mut [0; 4]));
+		}
+
+		(units == unit).then_some(self.s.len())
+	}
+	/// Returns the position of the first match of `pat`, measured in encoding units of `E`.
+	///
+	/// See [`str::find`] for more information.
+	///
+	/// ```
+	/// # use maxlen::{bstr, BStr, encoding::Cesu8};
+	/// let s: &BStr<16, Cesu8> = bstr!(16, Cesu8, "🦀=b");
+	/// assert_eq!(s.find("="), Some(4));
+	/// assert_eq!(s.find_unit("="), Some(6));
+	/// ```
+	pub fn find_unit(&self, pat: &str) -> Option<usize> {
+		self.s.find(pat).map(|i| E::length(&self.s[..i]))
+	}
+	/// Returns the position of the last match of `pat`, measured in encoding units of `E`.
+	///
+	/// See [`str::rfind`] for more information.
+	pub fn rfind_unit(&self, pat: &str) -> Option<usize> {
+		self.s.rfind(pat).map(|i| E::length(&self.s[..i]))
+	}
+	/// Returns the byte index of the end of the longest prefix whose length
+	/// in the `E` encoding does not exceed `target`.
+	///
+	/// The returned index is always on a char boundary, so `&self[..i]` never panics.
+	///
+	/// ```
+	/// # use maxlen::{bstr, BStr, encoding::Cesu8};
+	/// let s: &BStr<16, Cesu8> = bstr!(16, Cesu8, "a🦀b");
+	/// // the crab takes up 6 bytes in CESU-8, but only 4 in UTF-8
+	/// assert_eq!(s.floor_length_boundary(6), 1);
+	/// assert_eq!(s.floor_length_boundary(7), 5);
+	/// ```
+	pub fn floor_length_boundary(&self, target: usize) -> usize {
+		let mut length = 0;
+		for (i, c) in self.s.char_indices() {
+			length += E::length(c.encode_utf8(&mut [0; 4]));
+			if length > target {
+				return i;
+			}
+		}
+
+		self.s.len()
+	}
+	/// Returns the byte representation of the string in the `E` encoding.
+	///
+	/// Borrows the string's bytes if `E` is byte-identical to UTF-8
+	/// (see [`Encoding::BYTES_EQUAL_UTF8`]), and re-encodes it otherwise.
+	///
+	/// ```
+	/// # use maxlen::{bstr, BStr, encoding::Cesu8};
+	/// # use std::borrow::Cow;
+	/// let s: &BStr<16> = bstr!(16, "🦀");
+	/// assert!(matches!(s.to_encoded_cow(), Cow::Borrowed(b"\xF0\x9F\xA6\x80")));
+	/// let s: &BStr<16, Cesu8> = bstr!(16, Cesu8, "🦀");
+	/// assert_eq!(*s.to_encoded_cow(), *b"\xED\xA0\xBE\xED\xB6\x80");
+	/// ```
+	pub fn to_encoded_cow(&self) -> Cow<'_, [u8]> {
+		if E::BYTES_EQUAL_UTF8 {
+			Cow::Borrowed(self.s.as_bytes())
+		} else {
+			Cow::Owned(E::encode(&self.s))
+		}
+	}
+	/// Returns the string with leading and trailing whitespace removed and ASCII letters
+	/// converted to lower case.
+	///
+	/// Borrows the string if it is already normalized, and allocates otherwise.
+	///
+	/// ```
+	/// # use maxlen::{bstr, BStr};
+	/// # use std::borrow::Cow;
+	/// let s: &BStr<32> = bstr!(32, "  Content-Type ");
+	/// assert_eq!(*s.normalize(), *"content-type");
+	/// let s: &BStr<32> = bstr!(32, "content-type");
+	/// assert!(matches!(s.normalize(), Cow::Borrowed(_)));
+	/// ```
+	pub fn normalize(&self) -> Cow<'_, Self> {
+		let trimmed = self.s.trim();
+		if trimmed.len() == self.s.len() && !trimmed.bytes().any(|b| b.is_ascii_uppercase()) {
+			return Cow::Borrowed(self);
+		}
+
+		let mut s = String::from(trimmed);
+		s.make_ascii_lowercase();
+
+		// trimming never makes the string longer, and changing ASCII case preserves the length
+		Cow::Owned(unsafe { BString::from_string_unchecked(s) })
+	}
+	/// Returns a wrapper implementing [`Display`] that interprets the precision
+	/// as a number of encoding units of `E` instead of chars.
+	///
+	/// The string is truncated on a char boundary, as with [`floor_length_boundary`](Self::floor_length_boundary).
+	///
+	/// ```
+	/// # use maxlen::{bstr, BStr, encoding::Cesu8};
+	/// let s: &BStr<16, Cesu8> = bstr!(16, Cesu8, "a🦀b");
+	/// assert_eq!(format!("{:.6}", s.display_encoded()), "a");
+	/// assert_eq!(format!("{:.7}", s.display_encoded()), "a🦀");
+	/// assert_eq!(format!("{:>4.7}", s.display_encoded()), "  a🦀");
+	/// ```
+	pub fn display_encoded(&self) -> DisplayEncoded<'_, MAX, E> {
+		DisplayEncoded { s: self }
+	}
+	/// Appends `n` copies of this string onto the end of `out`, performing a runtime check.
+	///
+	/// Doesn't allocate if `out` already has enough capacity. On overflow `out` is left unchanged.
+	///
+	/// ```
+	/// # use maxlen::{bstr, BStr, BString};
+	/// let mut out = BString::<8>::from_str("ab").unwrap();
+	/// bstr!(2, "-=").repeat_into(3, &mut out).unwrap();
+	/// assert_eq!(out, "ab-=-=-=");
+	/// assert!(bstr!(2, "-").repeat_into(1, &mut out).is_err());
+	/// ```
+	pub fn repeat_into<const OUT: usize>(
+		&self,
+		n: usize,
+		out: &mut BString<OUT, E>,
+	) -> Result<(), LengthExceeded> {
+		let length = E::length(&self.s)
+			.checked_mul(n)
+			.and_then(|l| l.checked_add(out.encoded_len()))
+			.unwrap_or(usize::MAX);
+		if length > OUT {
+			return Err(LengthExceeded {
+				length,
+				maximum: OUT,
+				encoding: E::NAME,
+			});
+		}
+
+		if self.s.is_empty() {
+			return Ok(());
+		}
+
+		// the length was checked above, and the bytes are valid UTF-8
+		let bytes = unsafe { out.as_mut_vec() };
+		bytes.reserve(self.s.len() * n);
+		for _ in 0..n {
+			bytes.extend_from_slice(self.s.as_bytes());
+		}
+
+		Ok(())
+	}
+	/// Changes the `MAX` bound (and optionally the encoding type).
+	///
+	/// This involves a check whether the new bound is met.
+	pub fn change_max<E2: Encoding, const MAX2: usize>(
+		&self,
+	) -> Result<&BStr<MAX2, E2>, LengthExceeded> {
+		BStr::from_str(self)
+	}
+	/// Changes the `MAX` bound (and optionally the encoding type).
+	///
+	/// This involves a check whether the new bound is met.
+	pub fn change_max_mut<E2: Encoding, const MAX2: usize>(
+		&mut self,
+	) -> Result<&mut BStr<MAX2, E2>, LengthExceeded> {
+		BStr::from_str_mut(&mut self.s)
+	}
 }
 
 // Trait implementations relating BStr and BString
@@ -146,7 +589,7 @@ impl<E: Encoding, const MAX: usize> ToOwned for BStr<MAX, E> {
 	type Owned = BString<MAX, E>;
 
 	fn to_owned(&self) -> Self::Owned {
-		unsafe { BString::from_string_unchecked(self.to_string()) }
+		unsafe { BString::from_str_unchecked(&self.s) }
 	}
 }
 impl<E: Encoding, const MAX: usize> From<&BStr<MAX, E>> for BString<MAX, E> {
@@ -198,39 +641,40 @@ impl<E, const MAX: usize> Deref for BStr<MAX, E> {
 		&self.s
 	}
 }
-impl<E1: Encoding, E2: Encoding, const MAX1: usize, const MAX2: usize> PartialEq<BStr<MAX2, E2>>
-	for BStr<MAX1, E1>
-{
+impl<E1, E2, const MAX1: usize, const MAX2: usize> PartialEq<BStr<MAX2, E2>> for BStr<MAX1, E1> {
 	fn eq(&self, other: &BStr<MAX2, E2>) -> bool {
 		(**self).eq(&**other)
 	}
 }
-impl<E: Encoding, const MAX: usize> Eq for BStr<MAX, E> {}
-impl<E1: Encoding, E2: Encoding, const MAX1: usize, const MAX2: usize> PartialOrd<BStr<MAX2, E2>>
-	for BStr<MAX1, E1>
-{
-	fn partial_cmp(&self, other: &BStr<MAX2, E2>) -> Option<std::cmp::Ordering> {
+impl<E, const MAX: usize> Eq for BStr<MAX, E> {}
+impl<E1, E2, const MAX1: usize, const MAX2: usize> PartialOrd<BStr<MAX2, E2>> for BStr<MAX1, E1> {
+	fn partial_cmp(&self, other: &BStr<MAX2, E2>) -> Option<core::cmp::Ordering> {
 		(**self).partial_cmp(&**other)
 	}
 }
-impl<E: Encoding, const MAX: usize> Ord for BStr<MAX, E> {
-	fn cmp(&self, other: &BStr<MAX, E>) -> std::cmp::Ordering {
+impl<E, const MAX: usize> Ord for BStr<MAX, E> {
+	fn cmp(&self, other: &BStr<MAX, E>) -> core::cmp::Ordering {
 		(**self).cmp(&**other)
 	}
 }
-impl<E: Encoding, const MAX: usize> Default for &BStr<MAX, E> {
+impl<E, const MAX: usize> Default for &BStr<MAX, E> {
+	fn default() -> Self {
+		unsafe { BStr::from_str_unchecked(Default::default()) }
+	}
+}
+impl<E, const MAX: usize> Default for &mut BStr<MAX, E> {
 	fn default() -> Self {
-		unsafe { &BStr::from_str_unchecked(Default::default()) }
+		unsafe { BStr::from_str_mut_unchecked(Default::default()) }
 	}
 }
-impl<E: Encoding, const MAX: usize> Display for BStr<MAX, E> {
-	fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+impl<E, const MAX: usize> Display for BStr<MAX, E> {
+	fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
 		(**self).fmt(f)
 	}
 }
 macro_rules! impl_index {
 	($index:ty) => {
-		impl<E: Encoding, const MAX: usize> Index<$index> for BStr<MAX, E> {
+		impl<E, const MAX: usize> Index<$index> for BStr<MAX, E> {
 			type Output = Self;
 
 			fn index(&self, index: $index) -> &Self::Output {
@@ -247,37 +691,50 @@ impl_index! {RangeInclusive<usize>}
 impl_index! {RangeTo<usize>}
 impl_index! {RangeToInclusive<usize>}
 
-impl<E: Encoding, const MAX: usize> AsRef<OsStr> for BStr<MAX, E> {
+#[cfg(feature = "std")]
+impl<E, const MAX: usize> AsRef<OsStr> for BStr<MAX, E> {
 	fn as_ref(&self) -> &OsStr {
 		(**self).as_ref()
 	}
 }
-impl<E: Encoding, const MAX: usize> AsRef<Path> for BStr<MAX, E> {
+#[cfg(feature = "std")]
+impl<E, const MAX: usize> AsRef<Path> for BStr<MAX, E> {
 	fn as_ref(&self) -> &Path {
 		(**self).as_ref()
 	}
 }
-impl<E: Encoding, const MAX: usize> AsRef<[u8]> for BStr<MAX, E> {
+impl<E, const MAX: usize> AsRef<[u8]> for BStr<MAX, E> {
 	fn as_ref(&self) -> &[u8] {
 		(**self).as_ref()
 	}
 }
-impl<E: Encoding, const MAX: usize> AsRef<BSlice<u8, MAX>> for BStr<MAX, E> {
+impl<E, const MAX: usize> AsRef<BSlice<u8, MAX>> for BStr<MAX, E> {
 	fn as_ref(&self) -> &BSlice<u8, MAX> {
-		unsafe { BSlice::from_slice_unchecked(self.as_bytes()) }
+		self.as_bslice()
 	}
 }
-impl<E: Encoding, const MAX: usize> AsRef<Self> for BStr<MAX, E> {
+impl<E, const MAX: usize> AsRef<Self> for BStr<MAX, E> {
 	fn as_ref(&self) -> &Self {
 		self
 	}
 }
-impl<E: Encoding, const MAX: usize> AsRef<str> for BStr<MAX, E> {
+impl<E, const MAX: usize> AsRef<str> for BStr<MAX, E> {
 	fn as_ref(&self) -> &str {
-		&**self
+		self
+	}
+}
+impl<E, const MAX: usize> Borrow<str> for BStr<MAX, E> {
+	fn borrow(&self) -> &str {
+		self
+	}
+}
+/// Hashes exactly like the underlying [`str`], as required by [`Borrow<str>`].
+impl<E, const MAX: usize> Hash for BStr<MAX, E> {
+	fn hash<H: Hasher>(&self, state: &mut H) {
+		self.s.hash(state)
 	}
 }
-impl<E: Encoding, const MAX: usize> Clone for Box<BStr<MAX, E>> {
+impl<E, const MAX: usize> Clone for Box<BStr<MAX, E>> {
 	fn clone(&self) -> Self {
 		(**self).into()
 	}
@@ -292,92 +749,97 @@ impl<'a, E: Encoding, const MAX: usize> From<&'a mut BStr<MAX, E>> for Cow<'a, B
 		Self::Borrowed(value)
 	}
 }
-impl<'a, E: Encoding, const MAX: usize> From<&'a BStr<MAX, E>> for Cow<'a, str> {
+/// Borrows the string without copying it.
+///
+/// ```
+/// # use std::borrow::Cow;
+/// # use maxlen::{bstr, BStr};
+/// let s: &BStr<16> = bstr!(16, "borrowed");
+/// let cow: Cow<str> = s.into();
+/// assert!(matches!(cow, Cow::Borrowed(b) if b.as_ptr() == s.as_ptr()));
+/// ```
+impl<'a, E, const MAX: usize> From<&'a BStr<MAX, E>> for Cow<'a, str> {
 	fn from(value: &'a BStr<MAX, E>) -> Self {
 		Self::Borrowed(value)
 	}
 }
-impl<'a, E: Encoding, const MAX: usize> From<&'a mut BStr<MAX, E>> for Cow<'a, str> {
+impl<'a, E, const MAX: usize> From<&'a mut BStr<MAX, E>> for Cow<'a, str> {
 	fn from(value: &'a mut BStr<MAX, E>) -> Self {
 		Self::Borrowed(value)
 	}
 }
-impl<E: Encoding, const MAX: usize> From<&BStr<MAX, E>> for Arc<BStr<MAX, E>> {
+impl<E, const MAX: usize> From<&BStr<MAX, E>> for Arc<BStr<MAX, E>> {
 	fn from(value: &BStr<MAX, E>) -> Self {
 		let arc = Arc::<str>::from(&**value);
 
 		unsafe { Arc::from_raw(Arc::into_raw(arc) as *const BStr<MAX, E>) }
 	}
 }
-impl<E: Encoding, const MAX: usize> From<&mut BStr<MAX, E>> for Arc<BStr<MAX, E>> {
+impl<E, const MAX: usize> From<&mut BStr<MAX, E>> for Arc<BStr<MAX, E>> {
 	fn from(value: &mut BStr<MAX, E>) -> Self {
 		Self::from(&*value)
 	}
 }
-impl<E: Encoding, const MAX: usize> From<&BStr<MAX, E>> for Arc<str> {
+impl<E, const MAX: usize> From<&BStr<MAX, E>> for Arc<str> {
 	fn from(value: &BStr<MAX, E>) -> Self {
 		Arc::<str>::from(&**value)
 	}
 }
-impl<E: Encoding, const MAX: usize> From<&mut BStr<MAX, E>> for Arc<str> {
+impl<E, const MAX: usize> From<&mut BStr<MAX, E>> for Arc<str> {
 	fn from(value: &mut BStr<MAX, E>) -> Self {
 		Self::from(&*value)
 	}
 }
-impl<E: Encoding, const MAX: usize> From<&BStr<MAX, E>> for Box<BStr<MAX, E>> {
+impl<E, const MAX: usize> From<&BStr<MAX, E>> for Box<BStr<MAX, E>> {
 	fn from(value: &BStr<MAX, E>) -> Self {
 		let b = Box::<str>::from(&**value);
 
 		unsafe { Box::from_raw(Box::into_raw(b) as *mut BStr<MAX, E>) }
 	}
 }
-impl<E: Encoding, const MAX: usize> From<&mut BStr<MAX, E>> for Box<BStr<MAX, E>> {
+impl<E, const MAX: usize> From<&mut BStr<MAX, E>> for Box<BStr<MAX, E>> {
 	fn from(value: &mut BStr<MAX, E>) -> Self {
 		Self::from(&*value)
 	}
 }
-impl<E: Encoding, const MAX: usize> From<&BStr<MAX, E>> for Rc<BStr<MAX, E>> {
+impl<E, const MAX: usize> From<&BStr<MAX, E>> for Rc<BStr<MAX, E>> {
 	fn from(value: &BStr<MAX, E>) -> Self {
 		let b = Rc::<str>::from(&**value);
 
 		unsafe { Rc::from_raw(Rc::into_raw(b) as *mut BStr<MAX, E>) }
 	}
 }
-impl<E: Encoding, const MAX: usize> From<&mut BStr<MAX, E>> for Rc<BStr<MAX, E>> {
+impl<E, const MAX: usize> From<&mut BStr<MAX, E>> for Rc<BStr<MAX, E>> {
 	fn from(value: &mut BStr<MAX, E>) -> Self {
 		Self::from(&*value)
 	}
 }
-impl<E: Encoding, const MAX: usize> From<&BStr<MAX, E>> for Box<dyn std::error::Error> {
+impl<E, const MAX: usize> From<&BStr<MAX, E>> for Box<dyn core::error::Error> {
 	fn from(value: &BStr<MAX, E>) -> Self {
 		Self::from(&**value)
 	}
 }
-impl<E: Encoding, const MAX: usize> From<&mut BStr<MAX, E>> for Box<dyn std::error::Error> {
+impl<E, const MAX: usize> From<&mut BStr<MAX, E>> for Box<dyn core::error::Error> {
 	fn from(value: &mut BStr<MAX, E>) -> Self {
 		Self::from(&*value)
 	}
 }
-impl<E: Encoding, const MAX: usize> From<&BStr<MAX, E>>
-	for Box<dyn std::error::Error + Sync + Send>
-{
+impl<E, const MAX: usize> From<&BStr<MAX, E>> for Box<dyn core::error::Error + Sync + Send> {
 	fn from(value: &BStr<MAX, E>) -> Self {
 		Self::from(&**value)
 	}
 }
-impl<E: Encoding, const MAX: usize> From<&mut BStr<MAX, E>>
-	for Box<dyn std::error::Error + Sync + Send>
-{
+impl<E, const MAX: usize> From<&mut BStr<MAX, E>> for Box<dyn core::error::Error + Sync + Send> {
 	fn from(value: &mut BStr<MAX, E>) -> Self {
 		Self::from(&*value)
 	}
 }
-impl<E: Encoding, const MAX: usize> From<&BStr<MAX, E>> for String {
+impl<E, const MAX: usize> From<&BStr<MAX, E>> for String {
 	fn from(value: &BStr<MAX, E>) -> Self {
 		Self::from(&**value)
 	}
 }
-impl<E: Encoding, const MAX: usize> From<&mut BStr<MAX, E>> for String {
+impl<E, const MAX: usize> From<&mut BStr<MAX, E>> for String {
 	fn from(value: &mut BStr<MAX, E>) -> Self {
 		Self::from(&*value)
 	}
@@ -395,181 +857,305 @@ impl<E: Encoding, const MAX: usize> From<Cow<'_, BStr<MAX, E>>> for Box<BStr<MAX
 		}
 	}
 }
-impl<E: Encoding, const MAX: usize> From<Box<BStr<MAX, E>>> for Box<str> {
+impl<E, const MAX: usize> From<Box<BStr<MAX, E>>> for Box<str> {
 	fn from(value: Box<BStr<MAX, E>>) -> Self {
 		unsafe { Box::from_raw(Box::into_raw(value) as *mut str) }
 	}
 }
-impl<E: Encoding, const MAX: usize> Add<&BStr<MAX, E>> for String {
+impl<E, const MAX: usize> IntoIterator for Box<BStr<MAX, E>> {
+	type Item = char;
+	type IntoIter = IntoChars;
+
+	fn into_iter(self) -> Self::IntoIter {
+		let s = Box::<str>::from(self);
+
+		IntoChars {
+			front: 0,
+			back: s.len(),
+			s,
+		}
+	}
+}
+
+/// An owning iterator over the [`char`]s of a [`Box<BStr>`].
+///
+/// Created by the [`IntoIterator`] implementation of [`Box<BStr>`].
+#[derive(Debug, Clone)]
+pub struct IntoChars {
+	s: Box<str>,
+	front: usize,
+	back: usize,
+}
+impl Iterator for IntoChars {
+	type Item = char;
+
+	fn next(&mut self) -> Option<char> {
+		let c = self.s[self.front..self.back].chars().next()?;
+		self.front += c.len_utf8();
+
+		Some(c)
+	}
+	fn size_hint(&self) -> (usize, Option<usize>) {
+		self.s[self.front..self.back].chars().size_hint()
+	}
+}
+impl DoubleEndedIterator for IntoChars {
+	fn next_back(&mut self) -> Option<char> {
+		let c = self.s[self.front..self.back].chars().next_back()?;
+		self.back -= c.len_utf8();
+
+		Some(c)
+	}
+}
+impl FusedIterator for IntoChars {}
+
+/// Formats a [`BStr`] with the precision counted in encoding units of `E`.
+///
+/// Created by [`BStr::display_encoded`].
+#[derive(Debug)]
+pub struct DisplayEncoded<'a, const MAX: usize, E> {
+	s: &'a BStr<MAX, E>,
+}
+impl<E, const MAX: usize> Clone for DisplayEncoded<'_, MAX, E> {
+	fn clone(&self) -> Self {
+		*self
+	}
+}
+impl<E, const MAX: usize> Copy for DisplayEncoded<'_, MAX, E> {}
+impl<E: Encoding, const MAX: usize> Display for DisplayEncoded<'_, MAX, E> {
+	fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+		let s = match f.precision() {
+			Some(units) => &self.s.s[..self.s.floor_length_boundary(units)],
+			None => &self.s.s,
+		};
+
+		// every char takes up at least one unit, so the char-based precision
+		// applied by `pad` can't truncate the string any further
+		f.pad(s)
+	}
+}
+impl<E, const MAX: usize> Add<&BStr<MAX, E>> for String {
 	type Output = Self;
 
 	fn add(self, rhs: &BStr<MAX, E>) -> Self::Output {
 		self.add(&**rhs)
 	}
 }
-impl<'a, E: Encoding, const MAX: usize> Add<&'a BStr<MAX, E>> for Cow<'a, str> {
+impl<'a, E, const MAX: usize> Add<&'a BStr<MAX, E>> for Cow<'a, str> {
 	type Output = Self;
 
 	fn add(self, rhs: &'a BStr<MAX, E>) -> Self::Output {
 		self.add(&**rhs)
 	}
 }
-impl<E: Encoding, const MAX: usize> AddAssign<&BStr<MAX, E>> for String {
+impl<E, const MAX: usize> AddAssign<&BStr<MAX, E>> for String {
 	fn add_assign(&mut self, rhs: &BStr<MAX, E>) {
 		self.add_assign(&**rhs);
 	}
 }
-impl<'a, E: Encoding, const MAX: usize> AddAssign<&'a BStr<MAX, E>> for Cow<'a, str> {
+impl<'a, E, const MAX: usize> AddAssign<&'a BStr<MAX, E>> for Cow<'a, str> {
 	fn add_assign(&mut self, rhs: &'a BStr<MAX, E>) {
 		self.add_assign(&**rhs);
 	}
 }
-impl<'a, E: Encoding, const MAX: usize> Extend<&'a BStr<MAX, E>> for String {
+impl<'a, E, const MAX: usize> Extend<&'a BStr<MAX, E>> for String {
 	fn extend<T: IntoIterator<Item = &'a BStr<MAX, E>>>(&mut self, iter: T) {
 		for i in iter {
 			self.push_str(i);
 		}
 	}
 }
-impl<'a, E: Encoding, const MAX: usize> FromIterator<&'a BStr<MAX, E>> for Box<str> {
+impl<'a, E, const MAX: usize> FromIterator<&'a BStr<MAX, E>> for Box<str> {
 	fn from_iter<T: IntoIterator<Item = &'a BStr<MAX, E>>>(iter: T) -> Self {
 		let mut s = String::new();
 		s.extend(iter);
 		s.into_boxed_str()
 	}
 }
-impl<'a, E: Encoding, const MAX: usize> FromIterator<&'a BStr<MAX, E>> for String {
+impl<'a, E, const MAX: usize> FromIterator<&'a BStr<MAX, E>> for String {
 	fn from_iter<T: IntoIterator<Item = &'a BStr<MAX, E>>>(iter: T) -> Self {
 		let mut s = String::new();
 		s.extend(iter);
 		s
 	}
 }
-impl<'a, 'b, E: Encoding, const MAX: usize> FromIterator<&'a BStr<MAX, E>> for Cow<'b, str> {
+impl<'a, 'b, E, const MAX: usize> FromIterator<&'a BStr<MAX, E>> for Cow<'b, str> {
 	fn from_iter<T: IntoIterator<Item = &'a BStr<MAX, E>>>(iter: T) -> Self {
 		Cow::Owned(String::from_iter(iter))
 	}
 }
-impl<E: Encoding, const MAX: usize> FromIterator<Box<BStr<MAX, E>>> for Box<str> {
+impl<E, const MAX: usize> FromIterator<Box<BStr<MAX, E>>> for Box<str> {
 	fn from_iter<T: IntoIterator<Item = Box<BStr<MAX, E>>>>(iter: T) -> Self {
 		let mut s = String::new();
 		for i in iter {
-			s.push_str(&**i);
+			s.push_str(&i);
 		}
 		s.into_boxed_str()
 	}
 }
-impl<E: Encoding, const MAX: usize> PartialEq<BStr<MAX, E>> for String {
+impl<E, const MAX: usize> PartialEq<BStr<MAX, E>> for String {
 	fn eq(&self, other: &BStr<MAX, E>) -> bool {
 		self.eq(&**other)
 	}
 }
-impl<E: Encoding, const MAX: usize> PartialEq<&BStr<MAX, E>> for String {
+impl<E, const MAX: usize> PartialEq<&BStr<MAX, E>> for String {
 	fn eq(&self, other: &&BStr<MAX, E>) -> bool {
 		self.eq(&***other)
 	}
 }
-impl<E: Encoding, const MAX: usize> PartialEq<String> for BStr<MAX, E> {
+impl<E, const MAX: usize> PartialEq<String> for BStr<MAX, E> {
 	fn eq(&self, other: &String) -> bool {
 		(**self).eq(other)
 	}
 }
-impl<E: Encoding, const MAX: usize> PartialEq<String> for &BStr<MAX, E> {
+impl<E, const MAX: usize> PartialEq<String> for &BStr<MAX, E> {
 	fn eq(&self, other: &String) -> bool {
 		(**self).eq(other)
 	}
 }
-impl<E: Encoding, const MAX: usize> PartialEq<BStr<MAX, E>> for Cow<'_, str> {
+impl<E, const MAX: usize> PartialEq<BStr<MAX, E>> for Cow<'_, str> {
 	fn eq(&self, other: &BStr<MAX, E>) -> bool {
 		self.eq(&**other)
 	}
 }
-impl<E: Encoding, const MAX: usize> PartialEq<&BStr<MAX, E>> for Cow<'_, str> {
+impl<E, const MAX: usize> PartialEq<&BStr<MAX, E>> for Cow<'_, str> {
 	fn eq(&self, other: &&BStr<MAX, E>) -> bool {
 		self.eq(&***other)
 	}
 }
-impl<E: Encoding, const MAX: usize> PartialEq<Cow<'_, str>> for BStr<MAX, E> {
+impl<E, const MAX: usize> PartialEq<Cow<'_, str>> for BStr<MAX, E> {
 	fn eq(&self, other: &Cow<'_, str>) -> bool {
 		(**self).eq(other)
 	}
 }
-impl<E: Encoding, const MAX: usize> PartialEq<Cow<'_, str>> for &BStr<MAX, E> {
+impl<E, const MAX: usize> PartialEq<Cow<'_, str>> for &BStr<MAX, E> {
 	fn eq(&self, other: &Cow<'_, str>) -> bool {
 		(**self).eq(other)
 	}
 }
-impl<E: Encoding, const MAX: usize> PartialEq<BStr<MAX, E>> for OsStr {
+#[cfg(feature = "std")]
+impl<E, const MAX: usize> PartialEq<BStr<MAX, E>> for OsStr {
 	fn eq(&self, other: &BStr<MAX, E>) -> bool {
 		self.eq(&**other)
 	}
 }
-impl<E: Encoding, const MAX: usize> PartialEq<&BStr<MAX, E>> for OsStr {
+#[cfg(feature = "std")]
+impl<E, const MAX: usize> PartialEq<&BStr<MAX, E>> for OsStr {
 	fn eq(&self, other: &&BStr<MAX, E>) -> bool {
 		self.eq(&***other)
 	}
 }
-impl<E: Encoding, const MAX: usize> PartialEq<OsStr> for BStr<MAX, E> {
+#[cfg(feature = "std")]
+impl<E, const MAX: usize> PartialEq<OsStr> for BStr<MAX, E> {
 	fn eq(&self, other: &OsStr) -> bool {
 		(**self).eq(other)
 	}
 }
-impl<E: Encoding, const MAX: usize> PartialEq<OsStr> for &BStr<MAX, E> {
+#[cfg(feature = "std")]
+impl<E, const MAX: usize> PartialEq<OsStr> for &BStr<MAX, E> {
 	fn eq(&self, other: &OsStr) -> bool {
 		(**self).eq(other)
 	}
 }
-impl<E: Encoding, const MAX: usize> PartialEq<BStr<MAX, E>> for OsString {
+#[cfg(feature = "std")]
+impl<E, const MAX: usize> PartialEq<BStr<MAX, E>> for OsString {
 	fn eq(&self, other: &BStr<MAX, E>) -> bool {
 		self.eq(&**other)
 	}
 }
-impl<E: Encoding, const MAX: usize> PartialEq<&BStr<MAX, E>> for OsString {
+#[cfg(feature = "std")]
+impl<E, const MAX: usize> PartialEq<&BStr<MAX, E>> for OsString {
 	fn eq(&self, other: &&BStr<MAX, E>) -> bool {
 		self.eq(&***other)
 	}
 }
-impl<E: Encoding, const MAX: usize> PartialEq<OsString> for BStr<MAX, E> {
+#[cfg(feature = "std")]
+impl<E, const MAX: usize> PartialEq<OsString> for BStr<MAX, E> {
 	fn eq(&self, other: &OsString) -> bool {
 		(**self).eq(other)
 	}
 }
-impl<E: Encoding, const MAX: usize> PartialEq<OsString> for &BStr<MAX, E> {
+#[cfg(feature = "std")]
+impl<E, const MAX: usize> PartialEq<OsString> for &BStr<MAX, E> {
 	fn eq(&self, other: &OsString) -> bool {
 		(**self).eq(other)
 	}
 }
-impl<E: Encoding, const MAX: usize> PartialEq<BStr<MAX, E>> for str {
+impl<E, const MAX: usize> PartialEq<BStr<MAX, E>> for str {
 	fn eq(&self, other: &BStr<MAX, E>) -> bool {
 		self.eq(&**other)
 	}
 }
-impl<E: Encoding, const MAX: usize> PartialEq<&BStr<MAX, E>> for str {
+impl<E, const MAX: usize> PartialEq<&BStr<MAX, E>> for str {
 	fn eq(&self, other: &&BStr<MAX, E>) -> bool {
 		self.eq(&***other)
 	}
 }
-impl<E: Encoding, const MAX: usize> PartialEq<str> for BStr<MAX, E> {
+impl<E, const MAX: usize> PartialEq<str> for BStr<MAX, E> {
 	fn eq(&self, other: &str) -> bool {
 		(**self).eq(other)
 	}
 }
-impl<E: Encoding, const MAX: usize> PartialEq<str> for &BStr<MAX, E> {
+impl<E, const MAX: usize> PartialEq<str> for &BStr<MAX, E> {
 	fn eq(&self, other: &str) -> bool {
 		(**self).eq(other)
 	}
 }
-impl<E: Encoding, const MAX: usize> PartialEq<&str> for BStr<MAX, E> {
+impl<E, const MAX: usize> PartialEq<&str> for BStr<MAX, E> {
 	fn eq(&self, other: &&str) -> bool {
 		(**self).eq(*other)
 	}
 }
-impl<E: Encoding, const MAX: usize> PartialEq<&mut str> for BStr<MAX, E> {
+impl<E, const MAX: usize> PartialEq<&mut str> for BStr<MAX, E> {
 	fn eq(&self, other: &&mut str) -> bool {
 		(**self).eq(*other)
 	}
 }
-impl<E: Encoding, const MAX: usize> ToSocketAddrs for BStr<MAX, E> {
+impl<E, const MAX: usize> PartialOrd<str> for BStr<MAX, E> {
+	fn partial_cmp(&self, other: &str) -> Option<core::cmp::Ordering> {
+		self.s.partial_cmp(other)
+	}
+}
+impl<E, const MAX: usize> PartialOrd<BStr<MAX, E>> for str {
+	fn partial_cmp(&self, other: &BStr<MAX, E>) -> Option<core::cmp::Ordering> {
+		self.partial_cmp(&other.s)
+	}
+}
+impl<E, const MAX: usize> PartialOrd<String> for BStr<MAX, E> {
+	fn partial_cmp(&self, other: &String) -> Option<core::cmp::Ordering> {
+		self.s.partial_cmp(other.as_str())
+	}
+}
+impl<E, const MAX: usize> PartialOrd<BStr<MAX, E>> for String {
+	fn partial_cmp(&self, other: &BStr<MAX, E>) -> Option<core::cmp::Ordering> {
+		self.as_str().partial_cmp(&other.s)
+	}
+}
+/// Equal only if the string consists of exactly this one [`char`].
+impl<E, const MAX: usize> PartialEq<char> for BStr<MAX, E> {
+	fn eq(&self, other: &char) -> bool {
+		let mut chars = self.s.chars();
+		chars.next() == Some(*other) && chars.next().is_none()
+	}
+}
+impl<E, const MAX: usize> PartialEq<BStr<MAX, E>> for char {
+	fn eq(&self, other: &BStr<MAX, E>) -> bool {
+		other.eq(self)
+	}
+}
+/// Compares with the string consisting of this one [`char`].
+impl<E, const MAX: usize> PartialOrd<char> for BStr<MAX, E> {
+	fn partial_cmp(&self, other: &char) -> Option<core::cmp::Ordering> {
+		self.s.partial_cmp(other.encode_utf8(&mut [0; 4]))
+	}
+}
+impl<E, const MAX: usize> PartialOrd<BStr<MAX, E>> for char {
+	fn partial_cmp(&self, other: &BStr<MAX, E>) -> Option<core::cmp::Ordering> {
+		(*self.encode_utf8(&mut [0; 4])).partial_cmp(&other.s)
+	}
+}
+#[cfg(feature = "std")]
+impl<E, const MAX: usize> ToSocketAddrs for BStr<MAX, E> {
 	type Iter = <str as ToSocketAddrs>::Iter;
 
 	fn to_socket_addrs(&self) -> std::io::Result<Self::Iter> {
@@ -582,7 +1168,7 @@ mod serde_impls {
 	use super::*;
 	use serde::{Deserialize, Serialize, de::Visitor};
 
-	impl<'a, E: Encoding, const MAX: usize> Serialize for &'a BStr<MAX, E> {
+	impl<E, const MAX: usize> Serialize for BStr<MAX, E> {
 		fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
 		where
 			S: serde::Serializer,
@@ -595,7 +1181,7 @@ mod serde_impls {
 	impl<'de, E: Encoding + 'de, const MAX: usize> Visitor<'de> for BStrVisitor<E, MAX> {
 		type Value = &'de BStr<MAX, E>;
 
-		fn expecting(&self, formatter: &mut std::fmt::Formatter) -> std::fmt::Result {
+		fn expecting(&self, formatter: &mut core::fmt::Formatter) -> core::fmt::Result {
 			formatter.write_str("a string")
 		}
 		fn visit_borrowed_str<ER>(self, v: &'de str) -> Result<Self::Value, ER>
@@ -606,7 +1192,7 @@ mod serde_impls {
 				Ok(b) => Ok(b),
 				Err(_e) => Err(serde::de::Error::invalid_length(
 					v.len(),
-					&format!("{MAX}").as_str(),
+					&alloc::format!("{MAX}").as_str(),
 				)),
 			}
 		}
@@ -619,4 +1205,502 @@ mod serde_impls {
 			deserializer.deserialize_str(BStrVisitor(PhantomData))
 		}
 	}
+	impl<'de, E: Encoding, const MAX: usize> Deserialize<'de> for Box<BStr<MAX, E>> {
+		fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+		where
+			D: serde::Deserializer<'de>,
+		{
+			BString::deserialize(deserializer).map(Into::into)
+		}
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use crate::*;
+	use alloc::{
+		borrow::{Cow, ToOwned},
+		boxed::Box,
+		string::String,
+		vec::Vec,
+	};
+	use core::borrow::Borrow;
+
+	#[test]
+	fn test_box_into_chars() {
+		let b = Box::<BStr<16>>::from(BStr::from_str("aą🦀z").unwrap());
+		assert_eq!(b.clone().into_iter().collect::<String>(), "aą🦀z");
+		assert_eq!(b.into_iter().rev().collect::<String>(), "z🦀ąa");
+	}
+
+	#[test]
+	fn test_split_at_checked() {
+		let s = BStr::<16, encoding::Cesu8>::from_str("aą🦀z").unwrap();
+		let split = |mid| s.split_at_checked(mid).map(|(l, r)| (&**l, &**r));
+		assert_eq!(split(3), Some(("aą", "🦀z")));
+		assert_eq!(split(0), Some(("", "aą🦀z")));
+		assert_eq!(split(s.len()), Some(("aą🦀z", "")));
+
+		// in the middle of a multi-byte char
+		assert_eq!(split(2), None);
+		assert_eq!(split(5), None);
+		assert_eq!(split(s.len() + 1), None);
+	}
+
+	#[test]
+	fn test_split_at() {
+		let s = BStr::<16, encoding::Cesu8>::from_str("aą🦀z").unwrap();
+		let (l, r) = s.split_at(3);
+		assert_eq!((&**l, &**r), ("aą", "🦀z"));
+		let (l, r) = s.split_at(0);
+		assert_eq!((&**l, &**r), ("", "aą🦀z"));
+		let (l, r) = s.split_at(s.len());
+		assert_eq!((&**l, &**r), ("aą🦀z", ""));
+	}
+
+	#[test]
+	#[should_panic]
+	fn test_split_at_mid_char() {
+		let s = BStr::<16>::from_str("aą").unwrap();
+		let _ = s.split_at(2);
+	}
+
+	#[test]
+	fn test_floor_length_boundary() {
+		let s = BStr::<16, encoding::Utf8>::from_str("aą🦀z").unwrap();
+		assert_eq!(s.floor_length_boundary(0), 0);
+		assert_eq!(s.floor_length_boundary(2), 1);
+		assert_eq!(s.floor_length_boundary(6), 3);
+		assert_eq!(s.floor_length_boundary(7), 7);
+		assert_eq!(s.floor_length_boundary(100), s.len());
+
+		// the crab is 6 bytes in CESU-8
+		let s = BStr::<16, encoding::Cesu8>::from_str("aą🦀z").unwrap();
+		assert_eq!(s.floor_length_boundary(7), 3);
+		assert_eq!(s.floor_length_boundary(8), 3);
+		assert_eq!(s.floor_length_boundary(9), 7);
+		assert_eq!(s.floor_length_boundary(10), 8);
+
+		// and NUL is 2 bytes in Modified CESU-8
+		let s = BStr::<16, encoding::MCesu8>::from_str("a\0b").unwrap();
+		assert_eq!(s.floor_length_boundary(2), 1);
+		assert_eq!(s.floor_length_boundary(3), 2);
+		assert_eq!(s.floor_length_boundary(4), 3);
+	}
+
+	#[test]
+	fn test_byte_index_of_unit() {
+		let s = BStr::<32, encoding::Cesu8>::from_str("aą🦀z𐐷").unwrap();
+		assert_eq!(s.unit_len(), 16);
+
+		let expected = [
+			(0, Some(0)),
+			(1, Some(1)),
+			(2, None),
+			(3, Some(3)),
+			(4, None),
+			(8, None),
+			(9, Some(7)),
+			(10, Some(8)),
+			(13, None),
+			(16, Some(12)),
+			(17, None),
+		];
+		for (unit, byte) in expected {
+			assert_eq!(s.byte_index_of_unit(unit), byte, "unit {unit}");
+		}
+	}
+
+	#[test]
+	fn test_find_unit() {
+		let s = BStr::<32, encoding::Cesu8>::from_str("a🦀b🦀b").unwrap();
+		assert_eq!(s.find("b"), Some(5));
+		assert_eq!(s.find_unit("b"), Some(7));
+		assert_eq!(s.rfind("b"), Some(10));
+		assert_eq!(s.rfind_unit("b"), Some(14));
+		assert_eq!(s.find_unit("🦀"), Some(1));
+		assert_eq!(s.rfind_unit("🦀"), Some(8));
+		assert_eq!(s.find_unit("c"), None);
+		assert_eq!(s.rfind_unit("c"), None);
+
+		// in UTF-8 units are bytes
+		let s = BStr::<32>::from_str("a🦀b🦀b").unwrap();
+		assert_eq!(s.find_unit("b"), s.find("b"));
+		assert_eq!(s.rfind_unit("b"), s.rfind("b"));
+	}
+
+	#[test]
+	fn test_repeat_into() {
+		let pad = BStr::<6, encoding::Cesu8>::from_str("🦀").unwrap();
+		let mut out = BString::<16, encoding::Cesu8>::from_str("ab").unwrap();
+		pad.repeat_into(2, &mut out).unwrap();
+		assert_eq!(out, "ab🦀🦀");
+		pad.repeat_into(0, &mut out).unwrap();
+		assert_eq!(out.encoded_len(), 14);
+
+		// hitting the cap
+		let err = pad.repeat_into(1, &mut out).unwrap_err();
+		assert_eq!((err.length, err.maximum), (20, 16));
+		let err = pad.repeat_into(usize::MAX, &mut out).unwrap_err();
+		assert_eq!(err.length, usize::MAX);
+		assert_eq!(out, "ab🦀🦀");
+
+		// empty strings can be repeated any number of times
+		BStr::<4, encoding::Cesu8>::new()
+			.repeat_into(usize::MAX, &mut out)
+			.unwrap();
+		assert_eq!(out, "ab🦀🦀");
+	}
+
+	#[test]
+	fn test_default() {
+		let s: &'static BStr<8, encoding::Utf8> = Default::default();
+		assert!(s.is_empty());
+		assert_eq!(s.encoded_len(), 0);
+
+		let s: &'static mut BStr<8, encoding::Cesu8> = Default::default();
+		s.make_ascii_uppercase();
+		assert!(s.is_empty());
+	}
+
+	#[test]
+	fn test_unbounded_encoding() {
+		fn first_word<E, const MAX: usize>(s: &BStr<MAX, E>) -> &str {
+			s.split(' ').next().unwrap_or_default()
+		}
+		fn longest<'a, E, const MAX: usize>(
+			a: &'a BStr<MAX, E>,
+			b: &'a BStr<MAX, E>,
+		) -> &'a BStr<MAX, E> {
+			if a.len() >= b.len() { a } else { b }
+		}
+
+		#[derive(Debug)]
+		struct NotAnEncoding;
+		let a = unsafe { BStr::<16, NotAnEncoding>::from_str_unchecked("hello world") };
+		let b = BStr::<16, NotAnEncoding>::new();
+		assert_eq!(first_word(a), "hello");
+		assert_eq!(longest(a, b), "hello world");
+		assert!(a > b);
+		assert_eq!(alloc::format!("{}", &a[..5]), "hello");
+	}
+
+	#[test]
+	fn test_encoding_defaults() {
+		#[derive(Debug)]
+		struct Bytes;
+		impl encoding::Encoding for Bytes {
+			const BYTES_EQUAL_UTF8: bool = true;
+
+			fn length(s: &str) -> usize {
+				s.len()
+			}
+		}
+
+		let err = BStr::<4, Bytes>::from_str("a🦀").unwrap_err();
+		assert_eq!(err.encoding, "custom");
+		let s = BStr::<8, Bytes>::from_str("a🦀").unwrap();
+		assert_eq!(*s.to_encoded_cow(), *"a🦀".as_bytes());
+	}
+
+	#[test]
+	#[should_panic = "doesn't implement Encoding::encode"]
+	fn test_encoding_default_encode() {
+		#[derive(Debug)]
+		struct Chars;
+		impl encoding::Encoding for Chars {
+			fn length(s: &str) -> usize {
+				s.chars().count()
+			}
+		}
+
+		let _ = BStr::<8, Chars>::from_str("a🦀").unwrap().to_encoded_cow();
+	}
+
+	#[test]
+	fn test_char_len() {
+		let s = BStr::<32, encoding::Cesu8>::from_str("a🦀ą👍🏽").unwrap();
+		assert_eq!(s.char_len(), 5);
+		assert_eq!(s.len(), 15);
+		assert_eq!(s.encoded_len(), 21);
+		#[cfg(feature = "unicode")]
+		assert_eq!(s.grapheme_len(), 4);
+	}
+
+	#[test]
+	fn test_bsplit_inclusive() {
+		fn collect<'a>(i: impl Iterator<Item = &'a BStr<16>>) -> Vec<&'a str> {
+			i.map(|s| &**s).collect()
+		}
+
+		let s = BStr::<16>::from_str("one\ntwo\nthree").unwrap();
+		assert_eq!(
+			collect(s.bsplit_inclusive(|c| c == '\n')),
+			["one\n", "two\n", "three"]
+		);
+		let s = BStr::<16>::from_str("one\ntwo\n").unwrap();
+		assert_eq!(
+			collect(s.bsplit_inclusive(|c| c == '\n')),
+			["one\n", "two\n"]
+		);
+		assert_eq!(
+			collect(s.bsplit_inclusive(|c| c == '\n').rev()),
+			["two\n", "one\n"]
+		);
+		assert!(collect(BStr::<16>::new().bsplit_inclusive(|c| c == '\n')).is_empty());
+
+		let s = BStr::<16>::from_str("a\r\nb\rc\r\n").unwrap();
+		assert_eq!(
+			collect(s.bsplit_inclusive_str("\r\n")),
+			["a\r\n", "b\rc\r\n"]
+		);
+
+		// the str method with any pattern is still reachable through deref
+		let s = BString::<16>::from_str("a\r\nb\r\n").unwrap();
+		assert_eq!(
+			s.split_inclusive('\n').collect::<Vec<_>>(),
+			["a\r\n", "b\r\n"]
+		);
+		assert_eq!(
+			s.split_inclusive("\r\n").collect::<Vec<_>>(),
+			["a\r\n", "b\r\n"]
+		);
+	}
+
+	#[test]
+	fn test_matches() {
+		let s = BStr::<16>::from_str("abaababa").unwrap();
+		assert_eq!(
+			s.bmatches("aba").map(|m| &**m).collect::<Vec<_>>(),
+			["aba", "aba"]
+		);
+		let indices: Vec<_> = s.bmatch_indices("aba").map(|(i, m)| (i, &**m)).collect();
+		assert_eq!(indices, [(0, "aba"), (3, "aba")]);
+		assert_eq!(s.bmatches("c").count(), 0);
+
+		// the empty pattern matches at every char boundary
+		let s = BStr::<16>::from_str("aą").unwrap();
+		let indices: Vec<_> = s.bmatch_indices("").map(|(i, m)| (i, &**m)).collect();
+		assert_eq!(indices, [(0, ""), (1, ""), (3, "")]);
+
+		// the matches outlive a temporary pattern
+		let s = BStr::<16>::from_str("abab").unwrap();
+		let matches: Vec<&BStr<16>> = s.bmatches(&String::from("ab")).collect();
+		let indices: Vec<_> = s.bmatch_indices(&String::from("b")).collect();
+		assert_eq!(matches, ["ab", "ab"]);
+		assert_eq!(indices.len(), 2);
+	}
+
+	#[test]
+	fn test_make_ascii_titlecase() {
+		let mut s = BString::<64>::from_str("the quick  brown\tfox").unwrap();
+		s.make_ascii_titlecase();
+		assert_eq!(s, "The Quick  Brown\tFox");
+
+		let mut s = BString::<64>::from_str("\"quoted\" -dash 42nd ... mIxEd").unwrap();
+		s.make_ascii_titlecase();
+		assert_eq!(s, "\"Quoted\" -Dash 42Nd ... MIxEd");
+
+		// non-ASCII letters count as the first letter, but are not changed
+		let mut s = BString::<64, crate::encoding::Cesu8>::from_str("ąžuolas élan").unwrap();
+		let length = s.encoded_len();
+		s.make_ascii_titlecase();
+		assert_eq!(s, "ąžuolas élan");
+		assert_eq!(s.encoded_len(), length);
+
+		// non-ASCII non-letters are skipped just like ASCII punctuation
+		let mut s = BString::<64>::from_str("«hello» (hello) 😀ok\u{a0}non-breaking").unwrap();
+		s.make_ascii_titlecase();
+		assert_eq!(s, "«Hello» (Hello) 😀Ok\u{a0}Non-breaking");
+	}
+
+	#[test]
+	fn test_ignore_ascii_case() {
+		use crate::encoding::Cesu8;
+
+		let s = BStr::<16>::from_str("Content-Length").unwrap();
+		let lower = BStr::<32, Cesu8>::from_str("content-length").unwrap();
+		assert!(s.eq_ignore_ascii_case(lower));
+		assert!(s.eq_ignore_ascii_case(BStr::<14>::from_str("CONTENT-LENGTH").unwrap()));
+		assert!(!s.eq_ignore_ascii_case(BStr::<16>::from_str("Content-Type").unwrap()));
+
+		assert!(s.starts_with_ignore_ascii_case("CONTENT-"));
+		assert!(s.starts_with_ignore_ascii_case(""));
+		assert!(s.starts_with_ignore_ascii_case(lower));
+		assert!(!s.starts_with_ignore_ascii_case("content-length!"));
+
+		// non-ASCII characters are compared exactly
+		let s = BStr::<16>::from_str("Ärger").unwrap();
+		assert!(s.eq_ignore_ascii_case(BStr::<16>::from_str("ÄRGER").unwrap()));
+		assert!(!s.eq_ignore_ascii_case(BStr::<16>::from_str("äRGER").unwrap()));
+		assert!(s.starts_with_ignore_ascii_case("Är"));
+		assert!(!s.starts_with_ignore_ascii_case("är"));
+	}
+
+	#[test]
+	fn test_display_encoded() {
+		use crate::encoding::Cesu8;
+		use alloc::format;
+
+		// 😀 is 4 bytes in UTF-8, but 6 in CESU-8
+		let s = BStr::<32, Cesu8>::from_str("😀ab😀").unwrap();
+		assert_eq!(format!("{:.5}", s.display_encoded()), "");
+		assert_eq!(format!("{:.6}", s.display_encoded()), "😀");
+		assert_eq!(format!("{:.8}", s.display_encoded()), "😀ab");
+		assert_eq!(format!("{:.13}", s.display_encoded()), "😀ab");
+		assert_eq!(format!("{:.14}", s.display_encoded()), "😀ab😀");
+		assert_eq!(format!("{}", s.display_encoded()), "😀ab😀");
+		// the plain `Display` counts chars
+		assert_eq!(format!("{:.2}", s), "😀a");
+
+		// width is still counted in chars
+		assert_eq!(format!("{:-^6.8}", s.display_encoded()), "-😀ab--");
+		assert_eq!(format!("{:3.6}", s.display_encoded()), "😀  ");
+
+		let s = BStr::<32>::from_str("😀ab😀").unwrap();
+		assert_eq!(format!("{:.5}", s.display_encoded()), "😀a");
+	}
+
+	#[test]
+	fn test_to_encoded_cow() {
+		use crate::encoding::{Cesu8, MCesu8};
+
+		let s = BStr::<16>::from_str("a\0😀").unwrap();
+		let bytes = s.to_encoded_cow();
+		assert!(matches!(bytes, Cow::Borrowed(_)));
+		assert_eq!(*bytes, *s.as_bytes());
+
+		let s = BStr::<16, Cesu8>::from_str("a\0😀").unwrap();
+		let bytes = s.to_encoded_cow();
+		assert!(matches!(bytes, Cow::Owned(_)));
+		assert_eq!(*bytes, *b"a\0\xED\xA0\xBD\xED\xB8\x80");
+		assert_eq!(bytes.len(), s.encoded_len());
+
+		let s = BStr::<16, MCesu8>::from_str("a\0😀").unwrap();
+		let bytes = s.to_encoded_cow();
+		assert!(matches!(bytes, Cow::Owned(_)));
+		assert_eq!(*bytes, *b"a\xC0\x80\xED\xA0\xBD\xED\xB8\x80");
+		assert_eq!(bytes.len(), s.encoded_len());
+
+		// BMP characters are the same as in UTF-8
+		let s = BStr::<16, Cesu8>::from_str("ąž€").unwrap();
+		assert_eq!(*s.to_encoded_cow(), *"ąž€".as_bytes());
+	}
+
+	#[test]
+	fn test_box_into_shared() {
+		use crate::encoding::Cesu8;
+		use alloc::{rc::Rc, sync::Arc};
+
+		// provided by the blanket `From<Box<T>>` impls of `Arc` and `Rc`,
+		// which copy the contents into a new allocation
+		let b: Box<BStr<16, Cesu8>> = BStr::from_str("shared 😀").unwrap().into();
+		let arc = Arc::<BStr<16, Cesu8>>::from(b.clone());
+		assert_eq!(*arc, *b);
+		assert_eq!(arc.encoded_len(), 13);
+		let rc = Rc::<BStr<16, Cesu8>>::from(b);
+		assert_eq!(*rc, *arc);
+
+		let empty: Box<BStr<4>> = BStr::new().into();
+		assert!(Arc::<BStr<4>>::from(empty).is_empty());
+	}
+
+	#[test]
+	fn test_cmp_char() {
+		use core::cmp::Ordering;
+
+		let s = BStr::<8>::from_str("x").unwrap();
+		assert_eq!(*s, 'x');
+		assert_eq!('x', *s);
+		assert_ne!(*s, 'y');
+		let s = BStr::<8>::from_str("😀").unwrap();
+		assert_eq!(*s, '😀');
+		// same first char, but more of them
+		let s = BStr::<8>::from_str("xx").unwrap();
+		assert_ne!(*s, 'x');
+		assert_eq!(s.partial_cmp(&'x'), Some(Ordering::Greater));
+		assert_eq!('x'.partial_cmp(s), Some(Ordering::Less));
+		assert_eq!(s.partial_cmp(&'y'), Some(Ordering::Less));
+
+		let empty = BStr::<8>::new();
+		assert_ne!(*empty, 'x');
+		assert_ne!(*empty, '\0');
+		assert_eq!(empty.partial_cmp(&'\0'), Some(Ordering::Less));
+	}
+
+	#[test]
+	fn test_normalize() {
+		use crate::encoding::Cesu8;
+
+		let s = BStr::<16>::from_str("already-fine").unwrap();
+		assert!(matches!(s.normalize(), Cow::Borrowed(b) if core::ptr::eq(b, s)));
+		let s = BStr::<16>::from_str("ąžuolas 😀").unwrap();
+		assert!(matches!(s.normalize(), Cow::Borrowed(_)));
+		assert!(matches!(BStr::<16>::new().normalize(), Cow::Borrowed(_)));
+
+		let s = BStr::<16, Cesu8>::from_str("\t Mixed 😀\n").unwrap();
+		let n = s.normalize();
+		assert!(matches!(n, Cow::Owned(_)));
+		assert_eq!(*n, *"mixed 😀");
+		assert_eq!(n.encoded_len(), 12);
+		let s = BStr::<16>::from_str("UPPER").unwrap();
+		assert!(matches!(s.normalize(), Cow::Owned(o) if o == "upper"));
+		let s = BStr::<16>::from_str(" x").unwrap();
+		assert!(matches!(s.normalize(), Cow::Owned(o) if o == "x"));
+	}
+
+	#[cfg(feature = "serde")]
+	#[test]
+	fn test_serde_box() {
+		let s = BStr::<8, encoding::Cesu8>::from_str("a🦀").unwrap();
+		let boxed: Box<BStr<8, encoding::Cesu8>> = s.into();
+		let json = serde_json::to_string(&boxed).unwrap();
+		assert_eq!(json, "\"a🦀\"");
+		assert_eq!(json, serde_json::to_string(&s).unwrap());
+
+		let de: Box<BStr<8, encoding::Cesu8>> = serde_json::from_str(&json).unwrap();
+		assert_eq!(de, boxed);
+		// escapes force an owned string
+		let de: Box<BStr<8, encoding::Cesu8>> = serde_json::from_str("\"\\u0061🦀\"").unwrap();
+		assert_eq!(de, boxed);
+
+		// 7 bytes in CESU-8
+		assert!(serde_json::from_str::<Box<BStr<6, encoding::Cesu8>>>(&json).is_err());
+	}
+
+	#[test]
+	fn test_content_eq() {
+		let utf8 = BStr::<8>::from_str("a🦀").unwrap();
+		let cesu8 = BStr::<8, encoding::Cesu8>::from_str("a🦀").unwrap();
+		assert!(utf8.content_eq(cesu8));
+		assert!(cesu8.content_eq(utf8));
+		assert_eq!(utf8, cesu8);
+		assert_eq!(utf8.encoded_len(), 5);
+		assert_eq!(cesu8.encoded_len(), 7);
+
+		let other = BStr::<8, encoding::Cesu8>::from_str("a").unwrap();
+		assert!(!utf8.content_eq(other));
+		assert_ne!(utf8, other);
+	}
+
+	#[test]
+	fn test_hash() {
+		use core::hash::BuildHasher;
+		use std::{collections::HashMap, hash::RandomState};
+
+		let state = RandomState::new();
+		let s = BStr::<16, encoding::Cesu8>::from_str("aą🦀z").unwrap();
+		assert_eq!(state.hash_one(s), state.hash_one("aą🦀z"));
+		assert_eq!(state.hash_one(s.to_owned()), state.hash_one("aą🦀z"));
+		let boxed: Box<BStr<16, encoding::Cesu8>> = s.into();
+		assert_eq!(state.hash_one(&boxed), state.hash_one(s));
+
+		let mut map = HashMap::new();
+		map.insert(String::from("aą🦀z"), 1);
+		assert_eq!(map.get::<str>(s.borrow()), Some(&1));
+
+		let mut map = HashMap::new();
+		map.insert(boxed, 1);
+		assert_eq!(map.get(s), Some(&1));
+	}
 }
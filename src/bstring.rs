@@ -1,24 +1,37 @@
+#[cfg(feature = "std")]
+use crate::FromPathError;
 use crate::{
-	BSlice, BStr, LengthExceeded, const_checks,
-	encoding::{Encoding, Utf8},
+	BSlice, BStr, BVec, LengthExceeded, const_checks,
+	encoding::{Cesu8, Encoding, MCesu8, Utf8},
 };
-use std::{
-	borrow::{Borrow, Cow},
-	ffi::{OsStr, OsString},
+use alloc::{
+	borrow::{Cow, ToOwned},
+	boxed::Box,
+	collections::TryReserveError,
+	rc::Rc,
+	string::{FromUtf8Error, String},
+	sync::Arc,
+	vec::Vec,
+};
+use core::{
+	borrow::Borrow,
 	fmt::Display,
+	hash::{Hash, Hasher},
 	marker::PhantomData,
+	ops::{Add, AddAssign, Deref, DerefMut},
+	str::FromStr,
+};
+#[cfg(feature = "std")]
+use std::{
+	ffi::{OsStr, OsString},
 	net::ToSocketAddrs,
-	ops::{Deref, DerefMut},
 	path::{Path, PathBuf},
-	rc::Rc,
-	str::FromStr,
-	sync::Arc,
 };
 
 /// Bounded [`String`].
 ///
 /// Guaranteed to not be longer than `MAX` bytes in the [`E`][crate::encoding::Encoding] encoding representation.
-#[derive(Debug, Default, Hash)]
+#[derive(Debug, Default)]
 pub struct BString<const MAX: usize, E = Utf8> {
 	s: String,
 	phantom: PhantomData<fn(E) -> E>,
@@ -50,15 +63,103 @@ impl<E: Encoding, const MAX: usize> BString<MAX, E> {
 		}
 	}
 	/// Creates a `BString<MAX, E>` from a `&str`, performing a runtime check and allocating a new buffer.
+	///
+	/// The string is scanned once, and nothing is allocated if the check fails.
+	#[allow(clippy::should_implement_trait)]
 	pub fn from_str(s: &str) -> Result<Self, LengthExceeded> {
-		BStr::from_str(s).map(|s| s.to_owned())
+		BStr::<MAX, E>::from_str(s)?;
+
+		Ok(unsafe { Self::from_str_unchecked(s) })
 	}
 	/// Creates a `BString<MAX, E>` from a `String`, performing a runtime check.
+	///
+	/// The string is scanned once and its allocation is reused.
 	pub fn from_string(s: String) -> Result<Self, LengthExceeded> {
 		BStr::<MAX, E>::from_str(&s)?;
 
 		Ok(unsafe { Self::from_string_unchecked(s) })
 	}
+	/// Concatenates the parts with a separator between each of them, performing a runtime check.
+	///
+	/// The total length is computed up front, so nothing is allocated if the check fails.
+	///
+	/// ```
+	/// # use maxlen::{bstr, BStr, BString};
+	/// let parts: [&BStr<8>; 3] = [bstr!(8, "a"), bstr!(8, "bc"), bstr!(8, "d")];
+	/// let row = BString::<16>::join(&parts, ",").unwrap();
+	/// assert_eq!(row, "a,bc,d");
+	/// assert!(BString::<5>::join(&parts, ",").is_err());
+	/// ```
+	pub fn join<const PART: usize>(
+		parts: &[&BStr<PART, E>],
+		sep: &str,
+	) -> Result<Self, LengthExceeded> {
+		let separators = parts.len().saturating_sub(1);
+		let length = parts
+			.iter()
+			.map(|p| p.encoded_len())
+			.sum::<usize>()
+			.saturating_add(E::length(sep).saturating_mul(separators));
+		if length > MAX {
+			return Err(LengthExceeded {
+				length,
+				maximum: MAX,
+				encoding: E::NAME,
+			});
+		}
+
+		let capacity = parts.iter().map(|p| p.len()).sum::<usize>() + sep.len() * separators;
+		let mut s = String::with_capacity(capacity);
+		for (i, part) in parts.iter().enumerate() {
+			if i > 0 {
+				s.push_str(sep);
+			}
+			s.push_str(part);
+		}
+
+		Ok(unsafe { Self::from_string_unchecked(s) })
+	}
+	/// Concatenates string pieces from an iterator, stopping with an error at the first piece that doesn't fit.
+	///
+	/// On overflow, the reported length is the running length including the overflowing piece.
+	///
+	/// A `FromIterator` implementation for `Result<BString, LengthExceeded>` is not
+	/// possible because of the orphan rules, so this is the bounded equivalent of `collect`.
+	///
+	/// ```
+	/// # use maxlen::BString;
+	/// let s = BString::<8>::try_from_iter("a-b-c".split('-')).unwrap();
+	/// assert_eq!(s, "abc");
+	/// let err = BString::<2>::try_from_iter(["a", "bc", "d"]).unwrap_err();
+	/// assert_eq!(err.length, 3);
+	/// ```
+	pub fn try_from_iter<'a, I: IntoIterator<Item = &'a str>>(
+		iter: I,
+	) -> Result<Self, LengthExceeded> {
+		let mut s = String::new();
+		let mut length: usize = 0;
+		for piece in iter {
+			length = length.saturating_add(E::length(piece));
+			if length > MAX {
+				return Err(LengthExceeded {
+					length,
+					maximum: MAX,
+					encoding: E::NAME,
+				});
+			}
+			s.push_str(piece);
+		}
+
+		Ok(unsafe { Self::from_string_unchecked(s) })
+	}
+	/// Creates a `BString<MAX, E>` from a [`Path`], checking that it is valid UTF-8
+	/// and is not longer than `MAX`.
+	#[cfg(feature = "std")]
+	pub fn from_path(p: &Path) -> Result<Self, FromPathError> {
+		let s = p.to_str().ok_or(FromPathError::NotUtf8)?;
+
+		Ok(Self::from_str(s)?)
+	}
 	/// Gives the inner String.
 	pub fn into_inner(self) -> String {
 		self.s
@@ -67,13 +168,37 @@ impl<E: Encoding, const MAX: usize> BString<MAX, E> {
 	pub const fn as_string(&self) -> &String {
 		&self.s
 	}
+	/// Extracts an unbounded string slice containing the entire string.
+	///
+	/// See [`String::as_str`] for more information.
+	pub const fn as_str(&self) -> &str {
+		self.s.as_str()
+	}
+	/// Converts a `BString` into a mutable bounded string slice.
+	///
+	/// See [`String::as_mut_str`] for more information.
+	pub fn as_mut_str(&mut self) -> &mut BStr<MAX, E> {
+		// a `&mut str` only allows length-preserving edits
+		unsafe { BStr::from_str_mut_unchecked(&mut self.s) }
+	}
+	/// Returns a mutable reference to the contents of this `BString`.
+	///
+	/// See [`String::as_mut_vec`] for more information.
+	///
+	/// # Safety
+	///
+	/// The caller is responsible for making sure that the bytes are valid UTF-8
+	/// and not longer than `MAX` bytes in the given encoding when the borrow ends.
+	pub unsafe fn as_mut_vec(&mut self) -> &mut Vec<u8> {
+		unsafe { self.s.as_mut_vec() }
+	}
 	/// Relaxes the `MAX` bound, converting to a type with a bigger one.
 	///
 	/// This conversion is free and does not involve any checks. It is
 	/// asserted at compile time that the new `MAX` is bigger than before.
 	pub fn relax_max<const MAX2: usize>(self) -> BString<MAX2, E> {
 		// assert that MAX2 >= MAX at compile time
-		_ = <const_checks::Pair<MAX2, MAX> as const_checks::AssertGe>::VALID;
+		let () = <const_checks::Pair<MAX2, MAX> as const_checks::AssertGe>::VALID;
 
 		unsafe { BString::from_string_unchecked(self.s) }
 	}
@@ -87,10 +212,30 @@ impl<E: Encoding, const MAX: usize> BString<MAX, E> {
 	}
 	/// Returns this String’s capacity, in bytes.
 	///
+	/// This is the same as [`byte_capacity`](Self::byte_capacity). Note that it is measured
+	/// in UTF-8 bytes, not in encoding units of `E`, so it can be smaller than
+	/// [`encoded_len`](BStr::encoded_len) for encodings other than [`Utf8`].
+	///
 	/// See [`String::capacity`] for more information.
 	pub fn capacity(&self) -> usize {
 		self.s.capacity()
 	}
+	/// Returns this String’s capacity, in bytes.
+	///
+	/// The capacity is always at least [`len`](str::len), the length of the string in UTF-8 bytes,
+	/// but has no direct relation to the length in encoding units, which is what `MAX` bounds.
+	///
+	/// ```
+	/// # use maxlen::{BString, encoding::Cesu8};
+	/// let mut s = BString::<16, Cesu8>::from_str("🦀").unwrap();
+	/// s.shrink_to_fit();
+	/// // 4 bytes in UTF-8, but 6 in CESU-8
+	/// assert_eq!(s.byte_capacity(), 4);
+	/// assert_eq!(s.encoded_len(), 6);
+	/// ```
+	pub fn byte_capacity(&self) -> usize {
+		self.s.capacity()
+	}
 	/// Converts a `BString` into a byte vector.
 	///
 	/// See [`String::into_bytes`] for more information.
@@ -114,10 +259,54 @@ impl<E: Encoding, const MAX: usize> BString<MAX, E> {
 	/// See [`String::drain`] for more information.
 	pub fn drain<R>(&mut self, range: R)
 	where
-		R: std::ops::RangeBounds<usize>,
+		R: core::ops::RangeBounds<usize>,
 	{
 		self.s.drain(range);
 	}
+	/// Replaces the specified range in the string with the given string slice, performing a runtime check.
+	///
+	/// On overflow the string is left unchanged.
+	///
+	/// # Panics
+	///
+	/// Panics if the range is out of bounds or doesn't lie on [`char`] boundaries,
+	/// just like [`String::replace_range`].
+	pub fn replace_range<R>(&mut self, range: R, replace_with: &str) -> Result<(), LengthExceeded>
+	where
+		R: core::ops::RangeBounds<usize>,
+	{
+		use core::ops::Bound;
+
+		let range = (range.start_bound().cloned(), range.end_bound().cloned());
+		// panics on an invalid range, after which the bounds below can't overflow
+		let _ = &self.s[range];
+		let start = match range.0 {
+			Bound::Included(i) => i,
+			Bound::Excluded(i) => i + 1,
+			Bound::Unbounded => 0,
+		};
+		let end = match range.1 {
+			Bound::Included(i) => i + 1,
+			Bound::Excluded(i) => i,
+			Bound::Unbounded => self.s.len(),
+		};
+
+		// only the kept prefix and suffix are measured, not the removed part
+		let length = E::length(&self.s[..start])
+			.saturating_add(E::length(&self.s[end..]))
+			.saturating_add(E::length(replace_with));
+		if length > MAX {
+			return Err(LengthExceeded {
+				length,
+				maximum: MAX,
+				encoding: E::NAME,
+			});
+		}
+
+		self.s.replace_range(range, replace_with);
+
+		Ok(())
+	}
 	/// Creates a new empty [`BString`].
 	///
 	/// See [`String::new`] for more information.
@@ -136,12 +325,88 @@ impl<E: Encoding, const MAX: usize> BString<MAX, E> {
 			phantom: PhantomData,
 		}
 	}
+	/// Reserves capacity for at least `additional` bytes more than the current length.
+	///
+	/// The request is clamped so that the capacity never needs to exceed `MAX`,
+	/// and the usual speculative over-allocation is also capped at `MAX`.
+	///
+	/// See [`String::reserve`] for more information.
+	pub fn reserve(&mut self, additional: usize) {
+		self.s.reserve_exact(self.amortized_additional(additional));
+	}
+	/// Reserves the minimum capacity for at least `additional` bytes more than the current length.
+	///
+	/// The request is clamped so that the capacity never needs to exceed `MAX`.
+	///
+	/// See [`String::reserve_exact`] for more information.
+	pub fn reserve_exact(&mut self, additional: usize) {
+		self.s.reserve_exact(self.clamp_additional(additional));
+	}
+	/// Tries to reserve capacity for at least `additional` bytes more than the current length.
+	///
+	/// The request is clamped so that the capacity never needs to exceed `MAX`,
+	/// and the usual speculative over-allocation is also capped at `MAX`.
+	///
+	/// See [`String::try_reserve`] for more information.
+	pub fn try_reserve(&mut self, additional: usize) -> Result<(), TryReserveError> {
+		self.s
+			.try_reserve_exact(self.amortized_additional(additional))
+	}
+	/// Tries to reserve the minimum capacity for at least `additional` bytes more than the current length.
+	///
+	/// The request is clamped so that the capacity never needs to exceed `MAX`.
+	///
+	/// See [`String::try_reserve_exact`] for more information.
+	pub fn try_reserve_exact(&mut self, additional: usize) -> Result<(), TryReserveError> {
+		self.s.try_reserve_exact(self.clamp_additional(additional))
+	}
+	// The UTF-8 length is never bigger than the length in any of the encodings,
+	// so the string will never need more than `MAX` bytes of capacity.
+	fn clamp_additional(&self, additional: usize) -> usize {
+		additional.min(MAX.saturating_sub(self.s.len()))
+	}
+	// Like the amortized growth of `String::reserve` (at least doubling the capacity),
+	// but never going over `MAX`. `String::reserve` itself can't be used, because
+	// its minimum non-zero capacity of 8 overshoots small bounds.
+	fn amortized_additional(&self, additional: usize) -> usize {
+		let len = self.s.len();
+		let target = len
+			.saturating_add(self.clamp_additional(additional))
+			.max(self.s.capacity().saturating_mul(2))
+			.min(MAX);
+
+		if target <= self.s.capacity() {
+			0
+		} else {
+			target - len
+		}
+	}
 	/// Removes the last character from the string buffer and returns it.
 	///
 	/// See [`String::pop`] for more information.
 	pub fn pop(&mut self) -> Option<char> {
 		self.s.pop()
 	}
+	/// Inserts a string slice at the start of this [`BString`], performing a runtime check.
+	///
+	/// On overflow the string is left unchanged.
+	///
+	/// ```
+	/// # use maxlen::BString;
+	/// let mut id = BString::<8>::from_str("1234").unwrap();
+	/// id.prepend("usr-").unwrap();
+	/// assert_eq!(id, "usr-1234");
+	/// assert!(id.prepend("x").is_err());
+	/// ```
+	pub fn prepend(&mut self, s: &str) -> Result<(), LengthExceeded> {
+		self.replace_range(..0, s)
+	}
+	/// Inserts a [`char`] at the start of this [`BString`], performing a runtime check.
+	///
+	/// On overflow the string is left unchanged.
+	pub fn push_front(&mut self, ch: char) -> Result<(), LengthExceeded> {
+		self.prepend(ch.encode_utf8(&mut [0; 4]))
+	}
 	/// Removes a [`char`] from this [`BString`] at a byte position and returns it.
 	///
 	/// See [`String::remove`] for more information.
@@ -157,6 +422,43 @@ impl<E: Encoding, const MAX: usize> BString<MAX, E> {
 	{
 		self.s.retain(f);
 	}
+	/// Retains only the characters specified by the predicate, returning how many were removed.
+	///
+	/// See [`String::retain`] for more information.
+	pub fn retain_count<F>(&mut self, mut f: F) -> usize
+	where
+		F: FnMut(char) -> bool,
+	{
+		let mut removed = 0;
+		self.s.retain(|c| {
+			let keep = f(c);
+			if !keep {
+				removed += 1;
+			}
+			keep
+		});
+
+		removed
+	}
+	/// Converts this string to its ASCII upper case equivalent in-place.
+	///
+	/// See [`str::make_ascii_uppercase`] for more information.
+	pub fn make_ascii_uppercase(&mut self) {
+		self.as_mut_str().make_ascii_uppercase();
+	}
+	/// Converts this string to its ASCII lower case equivalent in-place.
+	///
+	/// See [`str::make_ascii_lowercase`] for more information.
+	pub fn make_ascii_lowercase(&mut self) {
+		self.as_mut_str().make_ascii_lowercase();
+	}
+	/// Converts the first letter of each whitespace-delimited word to its ASCII
+	/// upper case equivalent in-place.
+	///
+	/// See [`BStr::make_ascii_titlecase`] for more information.
+	pub fn make_ascii_titlecase(&mut self) {
+		self.as_mut_str().make_ascii_titlecase();
+	}
 	/// Shrinks the capacity of this [`BString`] with a lower bound.
 	///
 	/// See [`String::shrink_to`] for more information.
@@ -171,20 +473,151 @@ impl<E: Encoding, const MAX: usize> BString<MAX, E> {
 	}
 	/// Splits the string into two at the given byte index.
 	///
+	/// Both halves stay within `MAX`, as a part of a string is never longer
+	/// than the whole in any [`Encoding`].
+	///
 	/// See [`String::split_off`] for more information.
+	///
+	/// # Panics
+	///
+	/// Panics if `at` is not on a char boundary, or if it is beyond the end of the string.
 	pub fn split_off(&mut self, at: usize) -> Self {
 		Self {
 			s: self.s.split_off(at),
 			phantom: PhantomData,
 		}
 	}
+	/// Splits the string into lines and collects them into a bounded vector,
+	/// returning an error if there are more than `N` lines.
+	///
+	/// Lines are split the same way as [`str::lines`].
+	///
+	/// ```
+	/// # use maxlen::BString;
+	/// let s = BString::<32>::from_str("GET / HTTP/1.1\r\nHost: a\r\n").unwrap();
+	/// let lines = s.collect_lines::<4>().unwrap();
+	/// assert_eq!(lines, ["GET / HTTP/1.1", "Host: a"]);
+	/// assert!(s.collect_lines::<1>().is_err());
+	/// ```
+	pub fn collect_lines<const N: usize>(
+		&self,
+	) -> Result<BVec<BString<MAX, E>, N>, LengthExceeded> {
+		let count = self.s.lines().count();
+		if count > N {
+			return Err(LengthExceeded {
+				length: count,
+				maximum: N,
+				encoding: LengthExceeded::ELEMENTS,
+			});
+		}
+
+		// a line is never longer than the whole string
+		let lines = self
+			.s
+			.lines()
+			.map(|line| unsafe { BString::from_string_unchecked(String::from(line)) })
+			.collect();
+
+		Ok(unsafe { BVec::from_vec_unchecked(lines) })
+	}
+	/// Returns a new string with the [`char`]s in reverse order.
+	///
+	/// ```
+	/// # use maxlen::BString;
+	/// let s = BString::<16>::from_str("ab🦀ą").unwrap();
+	/// assert_eq!(s.reversed(), "ą🦀ba");
+	/// ```
+	pub fn reversed(&self) -> BString<MAX, E> {
+		// the same chars in a different order have the same length
+		unsafe { BString::from_string_unchecked(self.s.chars().rev().collect()) }
+	}
 	/// Shortens this [`BString`] to the specified length.
 	///
 	/// See [`String::truncate`] for more information.
 	pub fn truncate(&mut self, new_len: usize) {
 		self.s.truncate(new_len);
 	}
+	/// Shortens this [`BString`] to the largest char boundary not exceeding `max_len` bytes.
+	///
+	/// Unlike [`truncate`][Self::truncate], this never panics. Does nothing if
+	/// `max_len` is greater than the string's current length.
+	pub fn truncate_to_char_boundary(&mut self, max_len: usize) {
+		let new_len = self.s.floor_char_boundary(max_len);
+		self.s.truncate(new_len);
+	}
+	/// Appends a string slice onto the end of this `BString`, performing a runtime check.
+	///
+	/// On overflow, the original string is given back along with the error.
+	pub fn try_add(mut self, rhs: &str) -> Result<Self, (Self, LengthExceeded)> {
+		match self.try_push_str(rhs) {
+			Ok(()) => Ok(self),
+			Err(e) => Err((self, e)),
+		}
+	}
+	/// Appends characters from an iterator until the length bound is hit.
+	///
+	/// On overflow, returns `Err(n)` where `n` is the number of characters that
+	/// were pushed. These stay in the string. The character that didn't fit has already been
+	/// taken from the iterator and is dropped, but the characters after it are not consumed.
+	///
+	/// ```
+	/// # use maxlen::BString;
+	/// let mut s = BString::<4>::from_str("ab").unwrap();
+	/// let mut chars = "cdef".chars();
+	/// assert_eq!(s.try_extend_chars(chars.by_ref()), Err(2));
+	/// assert_eq!(s, "abcd");
+	/// // 'e' was rejected and consumed
+	/// assert_eq!(chars.as_str(), "f");
+	/// ```
+	pub fn try_extend_chars<I: IntoIterator<Item = char>>(&mut self, iter: I) -> Result<(), usize> {
+		let mut length = E::length(&self.s);
+		for (n, c) in iter.into_iter().enumerate() {
+			let char_length = E::length(c.encode_utf8(&mut [0; 4]));
+			if length.saturating_add(char_length) > MAX {
+				return Err(n);
+			}
+			length += char_length;
+			self.s.push(c);
+		}
+
+		Ok(())
+	}
+	fn try_push_str(&mut self, rhs: &str) -> Result<(), LengthExceeded> {
+		// check before pushing, so that an over-long `rhs` is never copied into the buffer
+		let length = E::length(&self.s).saturating_add(E::length(rhs));
+		if length > MAX {
+			return Err(LengthExceeded {
+				length,
+				maximum: MAX,
+				encoding: E::NAME,
+			});
+		}
+
+		self.s.push_str(rhs);
+
+		Ok(())
+	}
+}
+
+// only for the built-in encodings, in which the UTF-8 representation is never longer than
+// the encoded one. The `Encoding` contract doesn't guarantee that for custom encodings.
+macro_rules! impl_max_serialized_len {
+	($encoding:ty) => {
+		impl<const MAX: usize> BString<MAX, $encoding> {
+			/// Returns the worst-case length of this type serialized with a varint length prefix,
+			/// as done by [`postcard`](https://docs.rs/postcard).
+			///
+			/// Strings are serialized as UTF-8, which is never longer than `MAX` bytes
+			/// in this encoding, so this is the same as [`BVec::<u8, MAX>::max_serialized_len`].
+			pub const fn max_serialized_len() -> usize {
+				BVec::<u8, MAX>::max_serialized_len()
+			}
+		}
+	};
 }
+impl_max_serialized_len! {Utf8}
+impl_max_serialized_len! {Cesu8}
+impl_max_serialized_len! {MCesu8}
 
 // Trait implementations relating BStr and BString
 //////////////////////////////////////////////////
@@ -203,7 +636,7 @@ impl<E: Encoding, const MAX: usize> DerefMut for BString<MAX, E> {
 }
 impl<E: Encoding, const MAX: usize> Borrow<BStr<MAX, E>> for BString<MAX, E> {
 	fn borrow(&self) -> &BStr<MAX, E> {
-		&**self
+		self
 	}
 }
 impl<E: Encoding, const MAX: usize> AsRef<BStr<MAX, E>> for BString<MAX, E> {
@@ -244,11 +677,13 @@ impl<E: Encoding, const MAX: usize> Clone for BString<MAX, E> {
 		}
 	}
 }
+#[cfg(feature = "std")]
 impl<E: Encoding, const MAX: usize> AsRef<OsStr> for BString<MAX, E> {
 	fn as_ref(&self) -> &OsStr {
 		(**self).as_ref()
 	}
 }
+#[cfg(feature = "std")]
 impl<E: Encoding, const MAX: usize> AsRef<Path> for BString<MAX, E> {
 	fn as_ref(&self) -> &Path {
 		(**self).as_ref()
@@ -271,16 +706,22 @@ impl<E: Encoding, const MAX: usize> AsRef<Self> for BString<MAX, E> {
 }
 impl<E: Encoding, const MAX: usize> AsRef<str> for BString<MAX, E> {
 	fn as_ref(&self) -> &str {
-		&**self
+		self
 	}
 }
 impl<E: Encoding, const MAX: usize> Borrow<str> for BString<MAX, E> {
 	fn borrow(&self) -> &str {
-		&**self
+		self
+	}
+}
+/// Hashes exactly like the underlying [`String`], as required by [`Borrow<str>`].
+impl<E, const MAX: usize> Hash for BString<MAX, E> {
+	fn hash<H: Hasher>(&self, state: &mut H) {
+		self.s.hash(state)
 	}
 }
 impl<E: Encoding, const MAX: usize> Display for BString<MAX, E> {
-	fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+	fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
 		(**self).fmt(f)
 	}
 }
@@ -295,12 +736,12 @@ impl<E: Encoding, const MAX: usize> Eq for BString<MAX, E> {}
 impl<E1: Encoding, E2: Encoding, const MAX1: usize, const MAX2: usize> PartialOrd<BString<MAX2, E2>>
 	for BString<MAX1, E1>
 {
-	fn partial_cmp(&self, other: &BString<MAX2, E2>) -> Option<std::cmp::Ordering> {
+	fn partial_cmp(&self, other: &BString<MAX2, E2>) -> Option<core::cmp::Ordering> {
 		(**self).partial_cmp(&**other)
 	}
 }
 impl<E: Encoding, const MAX: usize> Ord for BString<MAX, E> {
-	fn cmp(&self, other: &BString<MAX, E>) -> std::cmp::Ordering {
+	fn cmp(&self, other: &BString<MAX, E>) -> core::cmp::Ordering {
 		(**self).cmp(&**other)
 	}
 }
@@ -311,6 +752,44 @@ impl<E: Encoding, const MAX: usize> Extend<BString<MAX, E>> for String {
 		}
 	}
 }
+/// # Panics
+///
+/// Panics if the result would exceed `MAX`. See [`BString::try_add`] for a fallible version.
+impl<E: Encoding, const MAX: usize> Add<&str> for BString<MAX, E> {
+	type Output = Self;
+
+	fn add(self, rhs: &str) -> Self::Output {
+		match self.try_add(rhs) {
+			Ok(s) => s,
+			Err((_, e)) => panic!("{e}"),
+		}
+	}
+}
+/// # Panics
+///
+/// Panics if the result would exceed `MAX`. See [`BString::try_add`] for a fallible version.
+impl<E: Encoding, const MAX: usize> AddAssign<&str> for BString<MAX, E> {
+	fn add_assign(&mut self, rhs: &str) {
+		if let Err(e) = self.try_push_str(rhs) {
+			panic!("{e}");
+		}
+	}
+}
+/// ```
+/// # use maxlen::BString;
+/// let parts: Vec<BString<8>> = ["foo", "bar", "baz"]
+///     .into_iter()
+///     .map(|s| BString::from_str(s).unwrap())
+///     .collect();
+/// assert_eq!(parts.into_iter().collect::<String>(), "foobarbaz");
+/// ```
+impl<E: Encoding, const MAX: usize> FromIterator<BString<MAX, E>> for String {
+	fn from_iter<T: IntoIterator<Item = BString<MAX, E>>>(iter: T) -> Self {
+		let mut s = String::new();
+		s.extend(iter);
+		s
+	}
+}
 impl<'a, E: Encoding, const MAX: usize> From<BString<MAX, E>> for Cow<'a, BStr<MAX, E>> {
 	fn from(value: BString<MAX, E>) -> Self {
 		Self::Owned(value)
@@ -353,23 +832,25 @@ impl<E: Encoding, const MAX: usize> From<BString<MAX, E>> for Arc<str> {
 		Arc::<str>::from(value.into_inner())
 	}
 }
-impl<E: Encoding, const MAX: usize> From<BString<MAX, E>> for Box<dyn std::error::Error> {
+impl<E: Encoding, const MAX: usize> From<BString<MAX, E>> for Box<dyn core::error::Error> {
 	fn from(value: BString<MAX, E>) -> Self {
 		Self::from(&**value)
 	}
 }
 impl<E: Encoding, const MAX: usize> From<BString<MAX, E>>
-	for Box<dyn std::error::Error + Send + Sync>
+	for Box<dyn core::error::Error + Send + Sync>
 {
 	fn from(value: BString<MAX, E>) -> Self {
 		Self::from(&**value)
 	}
 }
+#[cfg(feature = "std")]
 impl<E: Encoding, const MAX: usize> From<BString<MAX, E>> for OsString {
 	fn from(value: BString<MAX, E>) -> Self {
 		Self::from(value.into_inner())
 	}
 }
+#[cfg(feature = "std")]
 impl<E: Encoding, const MAX: usize> From<BString<MAX, E>> for PathBuf {
 	fn from(value: BString<MAX, E>) -> Self {
 		Self::from(value.into_inner())
@@ -387,6 +868,21 @@ impl<E: Encoding, const MAX: usize> From<BString<MAX, E>> for Rc<str> {
 		Self::from(value.into_inner())
 	}
 }
+impl<const MAX: usize> From<BString<MAX, Utf8>> for Vec<u8> {
+	fn from(value: BString<MAX, Utf8>) -> Self {
+		value.into_inner().into_bytes()
+	}
+}
+impl<const MAX: usize> TryFrom<BVec<u8, MAX>> for BString<MAX, Utf8> {
+	type Error = FromUtf8Error;
+
+	fn try_from(value: BVec<u8, MAX>) -> Result<Self, Self::Error> {
+		// the UTF-8 length is the byte length, which is already bounded
+		let s = String::from_utf8(value.into_inner())?;
+
+		Ok(unsafe { Self::from_string_unchecked(s) })
+	}
+}
 impl<E: Encoding, const MAX: usize> FromStr for BString<MAX, E> {
 	type Err = LengthExceeded;
 
@@ -394,6 +890,7 @@ impl<E: Encoding, const MAX: usize> FromStr for BString<MAX, E> {
 		Self::from_str(s)
 	}
 }
+#[cfg(feature = "std")]
 impl<E: Encoding, const MAX: usize> ToSocketAddrs for BString<MAX, E> {
 	type Iter = <String as ToSocketAddrs>::Iter;
 
@@ -421,41 +918,49 @@ impl<E: Encoding, const MAX: usize> PartialEq<Cow<'_, str>> for &BString<MAX, E>
 		(**self).eq(other)
 	}
 }
+#[cfg(feature = "std")]
 impl<E: Encoding, const MAX: usize> PartialEq<BString<MAX, E>> for OsStr {
 	fn eq(&self, other: &BString<MAX, E>) -> bool {
 		self.eq(&**other)
 	}
 }
+#[cfg(feature = "std")]
 impl<E: Encoding, const MAX: usize> PartialEq<&BString<MAX, E>> for OsStr {
 	fn eq(&self, other: &&BString<MAX, E>) -> bool {
 		self.eq(&***other)
 	}
 }
+#[cfg(feature = "std")]
 impl<E: Encoding, const MAX: usize> PartialEq<OsStr> for BString<MAX, E> {
 	fn eq(&self, other: &OsStr) -> bool {
 		(**self).eq(other)
 	}
 }
+#[cfg(feature = "std")]
 impl<E: Encoding, const MAX: usize> PartialEq<OsStr> for &BString<MAX, E> {
 	fn eq(&self, other: &OsStr) -> bool {
 		(**self).eq(other)
 	}
 }
+#[cfg(feature = "std")]
 impl<E: Encoding, const MAX: usize> PartialEq<BString<MAX, E>> for OsString {
 	fn eq(&self, other: &BString<MAX, E>) -> bool {
 		self.eq(&**other)
 	}
 }
+#[cfg(feature = "std")]
 impl<E: Encoding, const MAX: usize> PartialEq<&BString<MAX, E>> for OsString {
 	fn eq(&self, other: &&BString<MAX, E>) -> bool {
 		self.eq(&***other)
 	}
 }
+#[cfg(feature = "std")]
 impl<E: Encoding, const MAX: usize> PartialEq<OsString> for BString<MAX, E> {
 	fn eq(&self, other: &OsString) -> bool {
 		(**self).eq(other)
 	}
 }
+#[cfg(feature = "std")]
 impl<E: Encoding, const MAX: usize> PartialEq<OsString> for &BString<MAX, E> {
 	fn eq(&self, other: &OsString) -> bool {
 		(**self).eq(other)
@@ -496,6 +1001,58 @@ impl<E: Encoding, const MAX: usize> PartialEq<&mut str> for BString<MAX, E> {
 		(**self).eq(other)
 	}
 }
+impl<E: Encoding, const MAX: usize> PartialEq<String> for BString<MAX, E> {
+	fn eq(&self, other: &String) -> bool {
+		self.s.eq(other)
+	}
+}
+impl<E: Encoding, const MAX: usize> PartialEq<BString<MAX, E>> for String {
+	fn eq(&self, other: &BString<MAX, E>) -> bool {
+		self.eq(&other.s)
+	}
+}
+impl<E: Encoding, const MAX: usize> PartialOrd<str> for BString<MAX, E> {
+	fn partial_cmp(&self, other: &str) -> Option<core::cmp::Ordering> {
+		self.s.as_str().partial_cmp(other)
+	}
+}
+impl<E: Encoding, const MAX: usize> PartialOrd<BString<MAX, E>> for str {
+	fn partial_cmp(&self, other: &BString<MAX, E>) -> Option<core::cmp::Ordering> {
+		self.partial_cmp(other.s.as_str())
+	}
+}
+impl<E: Encoding, const MAX: usize> PartialOrd<String> for BString<MAX, E> {
+	fn partial_cmp(&self, other: &String) -> Option<core::cmp::Ordering> {
+		self.s.as_str().partial_cmp(other.as_str())
+	}
+}
+impl<E: Encoding, const MAX: usize> PartialOrd<BString<MAX, E>> for String {
+	fn partial_cmp(&self, other: &BString<MAX, E>) -> Option<core::cmp::Ordering> {
+		self.as_str().partial_cmp(other.s.as_str())
+	}
+}
+/// Equal only if the string consists of exactly this one [`char`].
+impl<E: Encoding, const MAX: usize> PartialEq<char> for BString<MAX, E> {
+	fn eq(&self, other: &char) -> bool {
+		(**self).eq(other)
+	}
+}
+impl<E: Encoding, const MAX: usize> PartialEq<BString<MAX, E>> for char {
+	fn eq(&self, other: &BString<MAX, E>) -> bool {
+		self.eq(&**other)
+	}
+}
+/// Compares with the string consisting of this one [`char`].
+impl<E: Encoding, const MAX: usize> PartialOrd<char> for BString<MAX, E> {
+	fn partial_cmp(&self, other: &char) -> Option<core::cmp::Ordering> {
+		(**self).partial_cmp(other)
+	}
+}
+impl<E: Encoding, const MAX: usize> PartialOrd<BString<MAX, E>> for char {
+	fn partial_cmp(&self, other: &BString<MAX, E>) -> Option<core::cmp::Ordering> {
+		self.partial_cmp(&**other)
+	}
+}
 impl<E: Encoding, const MAX: usize> TryFrom<String> for BString<MAX, E> {
 	type Error = LengthExceeded;
 
@@ -536,7 +1093,7 @@ mod serde_impls {
 	impl<'de, E: Encoding, const MAX: usize> Visitor<'de> for BStringVisitor<E, MAX> {
 		type Value = BString<MAX, E>;
 
-		fn expecting(&self, formatter: &mut std::fmt::Formatter) -> std::fmt::Result {
+		fn expecting(&self, formatter: &mut core::fmt::Formatter) -> core::fmt::Result {
 			formatter.write_str("a string")
 		}
 		fn visit_str<ER>(self, v: &str) -> Result<Self::Value, ER>
@@ -547,7 +1104,7 @@ mod serde_impls {
 				Ok(b) => Ok(b),
 				Err(_e) => Err(serde::de::Error::invalid_length(
 					v.len(),
-					&format!("{MAX}").as_str(),
+					&alloc::format!("{MAX}").as_str(),
 				)),
 			}
 		}
@@ -561,7 +1118,7 @@ mod serde_impls {
 				Ok(b) => Ok(b),
 				Err(_e) => Err(serde::de::Error::invalid_length(
 					len,
-					&format!("{MAX}").as_str(),
+					&alloc::format!("{MAX}").as_str(),
 				)),
 			}
 		}
@@ -575,3 +1132,788 @@ mod serde_impls {
 		}
 	}
 }
+
+#[cfg(feature = "rkyv")]
+pub use rkyv_impls::ArchivedBString;
+
+#[cfg(feature = "rkyv")]
+mod rkyv_impls {
+	use super::*;
+	use rkyv::{
+		Archive, Deserialize, Place, Portable, Serialize, SerializeUnsized,
+		bytecheck::CheckBytes,
+		rancor::{Fallible, Source},
+		string::{ArchivedString, StringResolver},
+	};
+
+	/// An archived [`BString`].
+	///
+	/// Validation (`check_bytes`) rejects archives longer than `MAX` bytes in the `E` encoding.
+	#[derive(Debug)]
+	#[repr(transparent)]
+	pub struct ArchivedBString<const MAX: usize, E = Utf8> {
+		s: ArchivedString,
+		phantom: PhantomData<fn(E) -> E>,
+	}
+
+	// SAFETY: `ArchivedBString` is `repr(transparent)` over a portable type.
+	unsafe impl<E, const MAX: usize> Portable for ArchivedBString<MAX, E> {}
+
+	// SAFETY: the string is checked to be valid before its length is checked against `MAX`.
+	unsafe impl<E: Encoding, C, const MAX: usize> CheckBytes<C> for ArchivedBString<MAX, E>
+	where
+		ArchivedString: CheckBytes<C>,
+		C: Fallible + ?Sized,
+		C::Error: Source,
+	{
+		unsafe fn check_bytes(value: *const Self, context: &mut C) -> Result<(), C::Error> {
+			unsafe { ArchivedString::check_bytes(value.cast(), context)? };
+
+			let length = E::length(unsafe { (*value).s.as_str() });
+			if length > MAX {
+				return Err(C::Error::new(LengthExceeded {
+					length,
+					maximum: MAX,
+					encoding: E::NAME,
+				}));
+			}
+
+			Ok(())
+		}
+	}
+
+	impl<E: Encoding, const MAX: usize> Deref for ArchivedBString<MAX, E> {
+		type Target = BStr<MAX, E>;
+
+		fn deref(&self) -> &Self::Target {
+			unsafe { BStr::from_str_unchecked(self.s.as_str()) }
+		}
+	}
+
+	impl<E: Encoding, const MAX: usize> Archive for BString<MAX, E> {
+		type Archived = ArchivedBString<MAX, E>;
+		type Resolver = StringResolver;
+
+		fn resolve(&self, resolver: Self::Resolver, out: Place<Self::Archived>) {
+			let out = unsafe { out.cast_unchecked::<ArchivedString>() };
+			ArchivedString::resolve_from_str(&self.s, resolver, out);
+		}
+	}
+	impl<E: Encoding, S: Fallible + ?Sized, const MAX: usize> Serialize<S> for BString<MAX, E>
+	where
+		S::Error: Source,
+		str: SerializeUnsized<S>,
+	{
+		fn serialize(&self, serializer: &mut S) -> Result<Self::Resolver, S::Error> {
+			ArchivedString::serialize_from_str(&self.s, serializer)
+		}
+	}
+	impl<E: Encoding, D: Fallible + ?Sized, const MAX: usize> Deserialize<BString<MAX, E>, D>
+		for ArchivedBString<MAX, E>
+	where
+		D::Error: Source,
+	{
+		fn deserialize(&self, _: &mut D) -> Result<BString<MAX, E>, D::Error> {
+			// checked again, since the archive may have been accessed without validation
+			BString::from_str(self.s.as_str()).map_err(D::Error::new)
+		}
+	}
+}
+
+#[cfg(feature = "borsh")]
+mod borsh_impls {
+	use super::*;
+	use borsh::{
+		BorshDeserialize, BorshSerialize,
+		io::{Error, ErrorKind, Read, Write},
+	};
+
+	impl<E: Encoding, const MAX: usize> BorshSerialize for BString<MAX, E> {
+		fn serialize<W: Write>(&self, writer: &mut W) -> borsh::io::Result<()> {
+			self.s.serialize(writer)
+		}
+	}
+	impl<E: Encoding, const MAX: usize> BorshDeserialize for BString<MAX, E> {
+		fn deserialize_reader<R: Read>(reader: &mut R) -> borsh::io::Result<Self> {
+			// The byte length prefix is checked before anything gets allocated.
+			// This relies on none of the encodings being shorter than UTF-8.
+			let prefix = u32::deserialize_reader(reader)?;
+			let length = prefix as usize;
+			if length > MAX {
+				return Err(Error::new(
+					ErrorKind::InvalidData,
+					LengthExceeded {
+						length,
+						maximum: MAX,
+						encoding: Utf8::NAME,
+					},
+				));
+			}
+
+			let s = String::deserialize_reader(&mut (&prefix.to_le_bytes()[..]).chain(reader))?;
+
+			BString::from_string(s).map_err(|e| Error::new(ErrorKind::InvalidData, e))
+		}
+	}
+}
+
+#[cfg(feature = "speedy")]
+mod speedy_impls {
+	use super::*;
+	use speedy::{Context, Readable, Reader, Writable, Writer};
+
+	impl<C: Context, E: Encoding, const MAX: usize> Writable<C> for BString<MAX, E> {
+		fn write_to<W: ?Sized + Writer<C>>(&self, writer: &mut W) -> Result<(), C::Error> {
+			self.s.write_to(writer)
+		}
+		fn bytes_needed(&self) -> Result<usize, C::Error> {
+			Writable::<C>::bytes_needed(&self.s)
+		}
+	}
+	impl<'a, C: Context, E: Encoding, const MAX: usize> Readable<'a, C> for BString<MAX, E> {
+		fn read_from<R: Reader<'a, C>>(reader: &mut R) -> Result<Self, C::Error> {
+			// The byte length prefix is checked before anything gets allocated.
+			// This relies on none of the encodings being shorter than UTF-8.
+			let length = reader.read_u32()? as usize;
+			if length > MAX {
+				return Err(speedy::Error::custom(LengthExceeded {
+					length,
+					maximum: MAX,
+					encoding: Utf8::NAME,
+				})
+				.into());
+			}
+
+			let s = reader.read_string(length)?;
+
+			BString::from_string(s).map_err(|e| speedy::Error::custom(e).into())
+		}
+		fn minimum_bytes_needed() -> usize {
+			<String as Readable<'a, C>>::minimum_bytes_needed()
+		}
+	}
+}
+
+#[cfg(feature = "arbitrary")]
+mod arbitrary_impls {
+	use super::*;
+	use arbitrary::{Arbitrary, Result, Unstructured};
+
+	impl<'a, E: Encoding, const MAX: usize> Arbitrary<'a> for BString<MAX, E> {
+		fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+			let length = u.int_in_range(0..=MAX)?;
+			let s = <&str>::arbitrary(u)?;
+
+			// take the longest prefix that fits in the chosen length
+			let mut end = 0;
+			let mut current = 0;
+			for c in s.chars() {
+				let char_length = E::length(c.encode_utf8(&mut [0; 4]));
+				if char_length > length - current {
+					break;
+				}
+				current += char_length;
+				end += c.len_utf8();
+			}
+
+			Ok(unsafe { Self::from_str_unchecked(&s[..end]) })
+		}
+	}
+}
+
+#[cfg(feature = "sqlx")]
+mod sqlx_impls {
+	use super::*;
+	use sqlx::{Database, Decode, Encode, Type, encode::IsNull, error::BoxDynError};
+
+	impl<DB: Database, E, const MAX: usize> Type<DB> for BString<MAX, E>
+	where
+		String: Type<DB>,
+	{
+		fn type_info() -> DB::TypeInfo {
+			<String as Type<DB>>::type_info()
+		}
+		fn compatible(ty: &DB::TypeInfo) -> bool {
+			<String as Type<DB>>::compatible(ty)
+		}
+	}
+	impl<'q, DB: Database, E, const MAX: usize> Encode<'q, DB> for BString<MAX, E>
+	where
+		String: Encode<'q, DB>,
+	{
+		fn encode(self, buf: &mut DB::ArgumentBuffer<'q>) -> Result<IsNull, BoxDynError> {
+			self.s.encode(buf)
+		}
+		fn encode_by_ref(&self, buf: &mut DB::ArgumentBuffer<'q>) -> Result<IsNull, BoxDynError> {
+			self.s.encode_by_ref(buf)
+		}
+		fn produces(&self) -> Option<DB::TypeInfo> {
+			self.s.produces()
+		}
+		fn size_hint(&self) -> usize {
+			self.s.size_hint()
+		}
+	}
+	impl<'r, DB: Database, E: Encoding, const MAX: usize> Decode<'r, DB> for BString<MAX, E>
+	where
+		String: Decode<'r, DB>,
+	{
+		fn decode(value: DB::ValueRef<'r>) -> Result<Self, BoxDynError> {
+			Ok(BString::from_string(String::decode(value)?)?)
+		}
+	}
+}
+
+#[cfg(feature = "valuable")]
+mod valuable_impls {
+	use super::*;
+	use valuable::{Valuable, Value, Visit};
+
+	impl<E: Encoding, const MAX: usize> Valuable for BString<MAX, E> {
+		fn as_value(&self) -> Value<'_> {
+			Value::String(&self.s)
+		}
+		fn visit(&self, visit: &mut dyn Visit) {
+			visit.visit_value(self.as_value())
+		}
+	}
+}
+
+#[cfg(feature = "rand")]
+mod rand_impls {
+	use super::*;
+	use rand::{
+		Rng,
+		distr::{Distribution, StandardUniform},
+	};
+
+	impl<E: Encoding, const MAX: usize> Distribution<BString<MAX, E>> for StandardUniform {
+		fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> BString<MAX, E> {
+			let length = rng.random_range(0..=MAX);
+			let widest = widest_char::<E>();
+
+			// push random chars until the chosen length is reached, skipping the ones that
+			// don't fit as long as there is room left for a character of any width
+			let mut s = String::new();
+			let mut current = 0;
+			while current < length {
+				let c: char = rng.random();
+				let char_length = E::length(c.encode_utf8(&mut [0; 4]));
+				if char_length <= length - current {
+					current += char_length;
+					s.push(c);
+				} else if length - current < widest {
+					break;
+				}
+			}
+
+			unsafe { BString::from_string_unchecked(s) }
+		}
+	}
+
+	/// Length of the widest character in the encoding, taking the largest code point
+	/// of each UTF-8 width as representative.
+	fn widest_char<E: Encoding>() -> usize {
+		['\u{7f}', '\u{7ff}', '\u{ffff}', '\u{10ffff}']
+			.map(|c| E::length(c.encode_utf8(&mut [0; 4])))
+			.into_iter()
+			.max()
+			.unwrap_or(0)
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use crate::{encoding::*, *};
+	use alloc::vec::Vec;
+
+	#[test]
+	fn test_reserve() {
+		let mut s = BString::<16, Cesu8>::new();
+		s.reserve(1000);
+		assert!(s.capacity() >= 16 && s.capacity() < 1000);
+		let mut s = BString::<16, Cesu8>::from_str("hello").unwrap();
+		s.reserve_exact(1000);
+		assert!(s.capacity() >= 16 && s.capacity() < 1000);
+		s.try_reserve(usize::MAX).unwrap();
+		s.try_reserve_exact(usize::MAX).unwrap();
+		assert!(s.capacity() < 1000);
+
+		// smaller than the allocator's minimum non-zero capacity
+		let mut s = BString::<4>::new();
+		s.reserve(1);
+		assert!(s.capacity() >= 1 && s.capacity() <= 4);
+		let mut s = BString::<4>::new();
+		s.try_reserve(4).unwrap();
+		assert_eq!(s.capacity(), 4);
+		let mut s = BString::<4>::from_str("a").unwrap();
+		for _ in 0..3 {
+			s.reserve(1);
+			s.push_front('a').unwrap();
+			assert!(s.capacity() <= 4);
+		}
+	}
+
+	#[test]
+	fn test_as_mut() {
+		let mut s = BString::<8>::from_str("hello").unwrap();
+		s.as_mut_str().make_ascii_uppercase();
+		assert_eq!(s, "HELLO");
+
+		unsafe { s.as_mut_vec().truncate(2) };
+		assert_eq!(s, "HE");
+	}
+
+	#[test]
+	fn test_cmp_unbounded() {
+		use alloc::string::String;
+		use core::cmp::Ordering::*;
+
+		let s = BString::<8>::from_str("b").unwrap();
+		let b: &BStr<8> = &s;
+		let (a, c) = (String::from("a"), String::from("c"));
+
+		assert_eq!(s.partial_cmp("a"), Some(Greater));
+		assert_eq!(s.partial_cmp("b"), Some(Equal));
+		assert_eq!(s.partial_cmp(&c), Some(Less));
+		assert_eq!("c".partial_cmp(&s), Some(Greater));
+		assert_eq!(a.partial_cmp(&s), Some(Less));
+
+		assert_eq!(b.partial_cmp("c"), Some(Less));
+		assert_eq!(b.partial_cmp(&a), Some(Greater));
+		assert_eq!("a".partial_cmp(b), Some(Less));
+		assert_eq!(c.partial_cmp(b), Some(Greater));
+		assert!(*b < c && s > *"a");
+	}
+
+	#[test]
+	fn test_truncate_to_char_boundary() {
+		let mut s = BString::<16>::from_str("aą🦀z").unwrap();
+		s.truncate_to_char_boundary(100);
+		assert_eq!(s, "aą🦀z");
+		// in the middle of the crab
+		s.truncate_to_char_boundary(5);
+		assert_eq!(s, "aą");
+		// exactly at a boundary
+		s.truncate_to_char_boundary(1);
+		assert_eq!(s, "a");
+		s.truncate_to_char_boundary(0);
+		assert_eq!(s, "");
+	}
+
+	#[test]
+	fn test_try_from_iter() {
+		let s = BString::<10, Cesu8>::try_from_iter(["ab", "😀", "", "cd"]).unwrap();
+		assert_eq!(s, "ab😀cd");
+		assert_eq!(s.encoded_len(), 10);
+
+		let err = BString::<9, Cesu8>::try_from_iter(["ab", "😀", "cd", "ef"]).unwrap_err();
+		assert_eq!(err.length, 10);
+		assert_eq!(err.maximum, 9);
+		assert_eq!(err.encoding, Cesu8::NAME);
+
+		assert!(BString::<0>::try_from_iter([""; 3]).unwrap().is_empty());
+	}
+
+	#[test]
+	fn test_split_off() {
+		// "ab" takes 2 bytes, 😀 takes 6 in CESU-8
+		let mut s = BString::<10, Cesu8>::from_str("ab😀cd").unwrap();
+		assert_eq!(s.encoded_len(), 10);
+
+		let mut tail = s.split_off(2);
+		assert_eq!(s, "ab");
+		assert_eq!(tail, "😀cd");
+		assert_eq!(tail.encoded_len(), 8);
+
+		let rest = tail.split_off(4);
+		assert_eq!(tail, "😀");
+		assert_eq!(tail.encoded_len(), 6);
+		assert_eq!(rest, "cd");
+	}
+
+	#[test]
+	#[should_panic]
+	fn test_split_off_boundary() {
+		let mut s = BString::<10, Cesu8>::from_str("ab😀cd").unwrap();
+		// in the middle of 😀
+		s.split_off(3);
+	}
+
+	#[test]
+	fn test_cmp_char() {
+		let s = BString::<8, Cesu8>::from_str("ą").unwrap();
+		assert_eq!(s, 'ą');
+		assert_eq!('ą', s);
+		assert_ne!(s, 'a');
+		assert!(s > 'a');
+		assert!('a' < s);
+		assert_ne!(BString::<8>::from_str("ąą").unwrap(), 'ą');
+		assert_ne!(BString::<8>::new(), 'ą');
+	}
+
+	#[test]
+	fn test_make_ascii_case() {
+		let mut s = BString::<19, Cesu8>::from_str("Hello wörld 😀").unwrap();
+		s.make_ascii_uppercase();
+		assert_eq!(s, "HELLO WöRLD 😀");
+		s.make_ascii_lowercase();
+		assert_eq!(s, "hello wörld 😀");
+		s.make_ascii_titlecase();
+		assert_eq!(s, "Hello Wörld 😀");
+		assert_eq!(s.encoded_len(), 19);
+	}
+
+	#[test]
+	fn test_retain_count() {
+		let mut s = BString::<32>::from_str("a1ą2😀3").unwrap();
+		assert_eq!(s.retain_count(|c| !c.is_ascii_digit()), 3);
+		assert_eq!(s, "aą😀");
+		assert_eq!(s.retain_count(char::is_alphabetic), 1);
+		assert_eq!(s, "aą");
+		assert_eq!(s.retain_count(|_| true), 0);
+		assert_eq!(s.retain_count(|_| false), 2);
+		assert!(s.is_empty());
+	}
+
+	#[test]
+	fn test_reversed() {
+		let s = BString::<32, Cesu8>::from_str("abc ąčę 😀€\0").unwrap();
+		let r = s.reversed();
+		assert_eq!(r, "\0€😀 ęčą cba");
+		assert_eq!(r.encoded_len(), s.encoded_len());
+		assert_eq!(r.len(), s.len());
+		assert_eq!(r.reversed(), s);
+		assert_eq!(BString::<4>::new().reversed(), "");
+	}
+
+	#[test]
+	fn test_collect_lines() {
+		let s = BString::<16>::from_str("one\ntwo\r\nthree").unwrap();
+		let lines = s.collect_lines::<4>().unwrap();
+		assert_eq!(lines, ["one", "two", "three"]);
+		let lines = s.collect_lines::<3>().unwrap();
+		assert_eq!(lines.len(), 3);
+		let err = s.collect_lines::<2>().unwrap_err();
+		assert_eq!(err.length, 3);
+		assert_eq!(err.maximum, 2);
+
+		// a trailing newline doesn't start another line
+		let s = BString::<16>::from_str("a\n\nb\n").unwrap();
+		assert_eq!(s.collect_lines::<3>().unwrap(), ["a", "", "b"]);
+		assert!(
+			BString::<16>::new()
+				.collect_lines::<0>()
+				.unwrap()
+				.is_empty()
+		);
+	}
+
+	#[cfg(feature = "std")]
+	#[test]
+	fn test_from_path() {
+		use std::path::Path;
+
+		let s = BString::<16>::from_path(Path::new("/usr/bin/ls")).unwrap();
+		assert_eq!(s, "/usr/bin/ls");
+		let err = BString::<16>::from_path(Path::new("/usr/local/share/doc")).unwrap_err();
+		assert!(matches!(err, FromPathError::LengthExceeded(e) if e.length == 20));
+
+		#[cfg(unix)]
+		{
+			use std::{ffi::OsStr, os::unix::ffi::OsStrExt};
+
+			let p = Path::new(OsStr::from_bytes(b"/tmp/\xff"));
+			assert!(matches!(
+				BString::<16>::from_path(p),
+				Err(FromPathError::NotUtf8)
+			));
+		}
+	}
+
+	#[test]
+	fn test_try_extend_chars() {
+		let mut s = BString::<8, Cesu8>::from_str("a").unwrap();
+		assert_eq!(s.try_extend_chars("bc".chars()), Ok(()));
+		assert_eq!(s, "abc");
+		// the crab takes 6 bytes in CESU-8 and doesn't fit
+		assert_eq!(s.try_extend_chars("dą🦀e".chars()), Err(2));
+		assert_eq!(s, "abcdą");
+		assert_eq!(s.try_extend_chars("f".chars()), Ok(()));
+		assert_eq!(s.try_extend_chars("gh".chars()), Err(1));
+		assert_eq!(s.try_extend_chars("h".chars()), Err(0));
+		assert_eq!(s, "abcdąfg");
+		assert_eq!(s.try_extend_chars(None), Ok(()));
+
+		// resuming after the error continues right after the rejected char
+		let mut s = BString::<3>::from_str("a").unwrap();
+		let mut chars = "bcde".chars();
+		assert_eq!(s.try_extend_chars(chars.by_ref()), Err(2));
+		assert_eq!(chars.next(), Some('e'));
+		assert_eq!(chars.next(), None);
+		assert_eq!(s, "abc");
+	}
+
+	#[cfg(feature = "rkyv")]
+	#[test]
+	fn test_rkyv() {
+		use alloc::string::ToString;
+		use rkyv::rancor::Error;
+
+		let bstring = BString::<9>::from_str("abcdefghi").unwrap();
+		let mut bytes = rkyv::to_bytes::<Error>(&bstring).unwrap();
+
+		let archived = rkyv::access::<rkyv::Archived<BString<9>>, Error>(&bytes).unwrap();
+		assert_eq!(**archived, *"abcdefghi");
+		let deserialized: BString<9> = rkyv::deserialize::<_, Error>(archived).unwrap();
+		assert_eq!(deserialized, bstring);
+
+		// fits in 8 bytes of UTF-8, but takes 12 in CESU-8
+		let crabs = BString::<8>::from_str("🦀🦀").unwrap();
+		let crab_bytes = rkyv::to_bytes::<Error>(&crabs).unwrap();
+		let err =
+			rkyv::access::<rkyv::Archived<BString<8, Cesu8>>, Error>(&crab_bytes).unwrap_err();
+		assert!(err.to_string().contains("exceeded"));
+		// deserializing checks again, in case validation was skipped
+		let unchecked =
+			unsafe { rkyv::access_unchecked::<rkyv::Archived<BString<8, Cesu8>>>(&crab_bytes) };
+		let err = rkyv::deserialize::<BString<8, Cesu8>, Error>(unchecked).unwrap_err();
+		assert!(err.to_string().contains("exceeded"));
+
+		// the root out-of-line `ArchivedString` is at the end of the buffer, its length (with
+		// 2 flag bits above the low 6 bits) being the first of the last 8 bytes. The string is
+		// followed by zeroed padding, so one more byte is still valid UTF-8.
+		let len = bytes.len();
+		assert_eq!(bytes[len - 8], 0x80 | 9);
+		bytes[len - 8] = 0x80 | 10;
+		let err = rkyv::access::<rkyv::Archived<BString<9>>, Error>(&bytes).unwrap_err();
+		assert!(err.to_string().contains("exceeded"));
+	}
+
+	#[cfg(feature = "borsh")]
+	#[test]
+	fn test_borsh() {
+		use alloc::string::ToString;
+
+		let bstring = BString::<5>::from_str("hello").unwrap();
+		let bytes = borsh::to_vec(&bstring).unwrap();
+		assert_eq!(borsh::from_slice::<BString<5>>(&bytes).unwrap(), bstring);
+		let err = borsh::from_slice::<BString<4>>(&bytes).unwrap_err();
+		assert!(err.to_string().contains("exceeded"));
+		// a huge length prefix with no data must fail on the prefix, not while allocating/reading
+		let err = borsh::from_slice::<BString<16>>(&u32::MAX.to_le_bytes()).unwrap_err();
+		assert!(err.to_string().contains("exceeded"));
+	}
+
+	#[cfg(feature = "speedy")]
+	#[test]
+	fn test_speedy() {
+		use alloc::string::ToString;
+		use speedy::{Readable, Writable};
+
+		let bstring = BString::<5>::from_str("hello").unwrap();
+		let bytes = bstring.write_to_vec().unwrap();
+		assert_eq!(BString::<5>::read_from_buffer(&bytes).unwrap(), bstring);
+		let err = BString::<4>::read_from_buffer(&bytes).unwrap_err();
+		assert!(err.to_string().contains("exceeded"));
+		// a huge length prefix with no data must fail on the prefix, not while allocating/reading
+		let err = BString::<16>::read_from_buffer(&u32::MAX.to_le_bytes()).unwrap_err();
+		assert!(err.to_string().contains("exceeded"));
+	}
+
+	#[cfg(feature = "rand")]
+	#[test]
+	fn test_rand() {
+		use rand::{Rng, RngCore};
+
+		// deterministic xorshift, good enough for checking the bounds
+		struct XorShift(u64);
+		impl RngCore for XorShift {
+			fn next_u32(&mut self) -> u32 {
+				self.next_u64() as u32
+			}
+			fn next_u64(&mut self) -> u64 {
+				self.0 ^= self.0 << 13;
+				self.0 ^= self.0 >> 7;
+				self.0 ^= self.0 << 17;
+				self.0
+			}
+			fn fill_bytes(&mut self, dst: &mut [u8]) {
+				rand::rand_core::impls::fill_bytes_via_next(self, dst)
+			}
+		}
+
+		let mut rng = XorShift(0x2545F4914F6CDD1D);
+		let mut lengths = [false; 7];
+		for _ in 0..1000 {
+			let s: BString<8, Cesu8> = rng.random();
+			assert!(Cesu8::length(&s) <= 8);
+			let s: BString<5, MCesu8> = rng.random();
+			assert!(MCesu8::length(&s) <= 5);
+			let v: BVec<u16, 6> = rng.random();
+			lengths[v.len()] = true;
+		}
+		// every possible length shows up
+		assert!(lengths.iter().all(|&l| l));
+	}
+
+	#[test]
+	fn test_join() {
+		let parts = ["a", "🦀", "bc"].map(|p| BStr::<8, Cesu8>::from_str(p).unwrap());
+
+		assert_eq!(BString::<0, Cesu8>::join(&parts[..0], ", ").unwrap(), "");
+		assert_eq!(BString::<6, Cesu8>::join(&parts[1..2], ", ").unwrap(), "🦀");
+		let s = BString::<13, Cesu8>::join(&parts, ", ").unwrap();
+		assert_eq!(s, "a, 🦀, bc");
+		assert_eq!(s.capacity(), s.len());
+
+		let err = BString::<12, Cesu8>::join(&parts, ", ").unwrap_err();
+		assert_eq!((err.length, err.maximum), (13, 12));
+	}
+
+	#[test]
+	fn test_prepend() {
+		let mut s = BString::<8, Cesu8>::new();
+		s.prepend("").unwrap();
+		assert!(s.is_empty());
+		s.prepend("b").unwrap();
+		s.push_front('a').unwrap();
+		assert_eq!(s, "ab");
+
+		// near full
+		s.prepend("🦀").unwrap();
+		assert_eq!(s.encoded_len(), 8);
+		let err = s.push_front('x').unwrap_err();
+		assert_eq!((err.length, err.maximum), (9, 8));
+		assert!(s.prepend("🦀").is_err());
+		assert_eq!(s, "🦀ab");
+	}
+
+	#[test]
+	fn test_replace_range() {
+		let mut s = BString::<12, Cesu8>::from_str("hello 🦀").unwrap();
+		// shorter
+		s.replace_range(..5, "hi").unwrap();
+		assert_eq!(s, "hi 🦀");
+		// equal
+		s.replace_range(3.., "🐍").unwrap();
+		assert_eq!(s, "hi 🐍");
+		// longer
+		s.replace_range(0..=1, "hey!").unwrap();
+		assert_eq!(s, "hey! 🐍");
+		assert_eq!(s.encoded_len(), 11);
+
+		let err = s.replace_range(..1, "abc").unwrap_err();
+		assert_eq!((err.length, err.maximum), (13, 12));
+		assert_eq!(s, "hey! 🐍");
+
+		use core::ops::Bound;
+		s.replace_range((Bound::Excluded(0), Bound::Included(3)), "")
+			.unwrap();
+		assert_eq!(s, "h 🐍");
+	}
+
+	#[test]
+	#[should_panic = "char boundary"]
+	fn test_replace_range_boundary() {
+		let mut s = BString::<10>::from_str("🦀").unwrap();
+		let _ = s.replace_range(1.., "");
+	}
+
+	#[cfg(feature = "valuable")]
+	#[test]
+	fn test_valuable() {
+		use valuable::{Valuable, Value};
+
+		let s = BString::<8, Cesu8>::from_str("hello").unwrap();
+		assert!(matches!(s.as_value(), Value::String("hello")));
+	}
+
+	#[test]
+	fn test_add() {
+		let s = BString::<8, Cesu8>::from_str("foo").unwrap();
+		let s = s.try_add("bar").unwrap();
+		assert_eq!(s, "foobar");
+		let (s, err) = s.try_add("🦀").unwrap_err();
+		assert_eq!(s, "foobar");
+		assert_eq!(err.length, 12);
+
+		let mut s = s + "ba";
+		assert_eq!(s, "foobarba");
+		s += "";
+		assert_eq!(s, "foobarba");
+	}
+
+	#[test]
+	#[should_panic = "exceeded"]
+	fn test_add_overflow() {
+		let mut s = BString::<4>::from_str("foo").unwrap();
+		s += "bar";
+	}
+
+	#[test]
+	fn test_add_overflow_keeps_capacity() {
+		let mut s = BString::<4>::from_str("foo").unwrap();
+		s.shrink_to_fit();
+		let capacity = s.capacity();
+
+		let long = "a".repeat(10_000);
+		let (mut s, _) = s.try_add(&long).unwrap_err();
+		assert_eq!(s.capacity(), capacity);
+
+		let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| s += &long));
+		assert!(result.is_err());
+		assert_eq!(s, "foo");
+		assert_eq!(s.capacity(), capacity);
+	}
+
+	#[test]
+	fn test_into_bytes() {
+		let s = BString::<8>::from_str("ąčę").unwrap();
+		let ptr = s.as_ptr();
+		let bytes = Vec::<u8>::from(s);
+		assert_eq!(bytes, "ąčę".as_bytes());
+		assert_eq!(bytes.as_ptr(), ptr);
+	}
+
+	#[test]
+	fn test_try_from_bvec() {
+		let s = BString::<8>::try_from(BVec::<u8, 8>::from_slice("ąčę".as_bytes()).unwrap());
+		assert_eq!(s.unwrap(), "ąčę");
+		let s = BString::<8>::try_from(BVec::<u8, 8>::new()).unwrap();
+		assert!(s.is_empty());
+
+		let err = BString::<8>::try_from(bvec![b'a', 0xFF, b'b']).unwrap_err();
+		assert_eq!(err.into_bytes(), [b'a', 0xFF, b'b']);
+	}
+
+	#[cfg(feature = "arbitrary")]
+	#[test]
+	fn test_arbitrary() {
+		use alloc::boxed::Box;
+		use arbitrary::{Arbitrary, Unstructured};
+
+		// some deterministic pseudo-random data
+		let mut state = 0x2545F491u32;
+		let data: Vec<u8> = (0..4096)
+			.map(|_| {
+				state ^= state << 13;
+				state ^= state >> 17;
+				state ^= state << 5;
+				state as u8
+			})
+			.collect();
+
+		let mut u = Unstructured::new(&data);
+		while !u.is_empty() {
+			let s = BString::<8, Cesu8>::arbitrary(&mut u).unwrap();
+			assert!(Cesu8::length(&s) <= 8);
+			let s = BString::<5, MCesu8>::arbitrary(&mut u).unwrap();
+			assert!(MCesu8::length(&s) <= 5);
+			let v = BVec::<u16, 6>::arbitrary(&mut u).unwrap();
+			assert!(v.len() <= 6);
+			let b = Box::<BSlice<u8, 3>>::arbitrary(&mut u).unwrap();
+			assert!(b.len() <= 3);
+		}
+	}
+}
@@ -1,11 +1,15 @@
-use std::{
-	borrow::{Borrow, BorrowMut, Cow},
+use alloc::{
+	borrow::Cow, boxed::Box, collections::VecDeque, ffi::CString, rc::Rc, string::String,
+	sync::Arc, vec::Vec,
+};
+use core::{
+	borrow::{Borrow, BorrowMut},
+	ffi::CStr,
+	num::NonZero,
 	ops::{Deref, DerefMut, RangeBounds},
-	rc::Rc,
-	sync::Arc,
 };
 
-use crate::{BSlice, LengthExceeded, const_checks};
+use crate::{BSlice, LengthExceeded, LengthOutOfBounds, const_checks};
 
 /// Bounded [`Vec`].
 ///
@@ -38,14 +42,14 @@ macro_rules! bvec {
 		// so we have to separate them. Clever workaround!
 		struct _Helper<const N: usize>;
 		impl<const N: usize> _Helper<N> {
-			fn _helper<T, const MAX: usize>(s: ::std::vec::Vec<T>) -> $crate::BVec<T, MAX> {
+			fn _helper<T, const MAX: usize>(s: $crate::__vec::Vec<T>) -> $crate::BVec<T, MAX> {
 				// compile time check
 				_ = <$crate::const_checks::Pair<MAX, N> as $crate::const_checks::AssertGe>::VALID;
 
 				unsafe { $crate::BVec::from_vec_unchecked(s) }
 			}
 		}
-		_Helper::<$n>::_helper(::std::vec![$elem; $n])
+		_Helper::<$n>::_helper($crate::__vec![$elem; $n])
 	}};
 	($($x:expr),+ $(,)?) => {{
 		// helper struct/method to infer MAX for the compile-time check
@@ -55,7 +59,7 @@ macro_rules! bvec {
 		// so we have to separate them. Clever workaround!
 		struct _Helper<const N: usize>;
 		impl<const N: usize> _Helper<N> {
-			fn _helper<T, const MAX: usize>(s: ::std::vec::Vec<T>) -> $crate::BVec<T, MAX> {
+			fn _helper<T, const MAX: usize>(s: $crate::__vec::Vec<T>) -> $crate::BVec<T, MAX> {
 				// compile time check
 				_ = <$crate::const_checks::Pair<MAX, N> as $crate::const_checks::AssertGe>::VALID;
 
@@ -64,7 +68,7 @@ macro_rules! bvec {
 		}
 		// another banger workaround to get the number of repetitions as a const
 		const _N: usize = 0 $( + { let _ = $x; 1 })*;
-		_Helper::<_N>::_helper(::std::vec![$($x),+])
+		_Helper::<_N>::_helper($crate::__vec![$($x),+])
 	}};
 }
 
@@ -79,6 +83,8 @@ impl<T, const MAX: usize> BVec<T, MAX> {
 	}
 	/// Creates a `BVec<T, MAX>` from a slice without any checks, allocating a new buffer.
 	///
+	/// Performs a single allocation with a capacity of exactly `s.len()`.
+	///
 	/// # Safety
 	///
 	/// The caller is responsible for making sure that the slice is definitely not longer than `MAX` elements.
@@ -86,14 +92,40 @@ impl<T, const MAX: usize> BVec<T, MAX> {
 	where
 		T: Clone,
 	{
-		Self { s: s.to_owned() }
+		let mut v = Vec::with_capacity(s.len());
+		v.extend_from_slice(s);
+
+		Self { s: v }
 	}
 	/// Creates a `BVec<T, MAX>` from a slice, performing a runtime check and allocating a new buffer.
+	///
+	/// Nothing is allocated if the check fails, otherwise a single allocation
+	/// with a capacity of exactly `s.len()` is performed.
 	pub fn from_slice(s: &[T]) -> Result<Self, LengthExceeded>
 	where
 		T: Clone,
 	{
-		BSlice::from_slice(s).map(|s| s.to_owned())
+		BSlice::<T, MAX>::from_slice(s)?;
+
+		Ok(unsafe { Self::from_slice_unchecked(s) })
+	}
+	/// Creates a `BVec<T, MAX>` of `n` clones of `value`, performing a runtime check.
+	///
+	/// This is the runtime-checked form of `bvec![value; n]`, for when `n` is not a constant.
+	/// Nothing is allocated if the check fails.
+	pub fn from_elem(value: T, n: usize) -> Result<Self, LengthExceeded>
+	where
+		T: Clone,
+	{
+		if n > MAX {
+			return Err(LengthExceeded {
+				length: n,
+				maximum: MAX,
+				encoding: LengthExceeded::ELEMENTS,
+			});
+		}
+
+		Ok(unsafe { Self::from_vec_unchecked(alloc::vec![value; n]) })
 	}
 	/// Creates a `BVec<T, MAX>` from a `Vec<T>`, performing a runtime check.
 	pub fn from_vec(s: Vec<T>) -> Result<Self, LengthExceeded> {
@@ -115,7 +147,7 @@ impl<T, const MAX: usize> BVec<T, MAX> {
 	/// asserted at compile time that the new `MAX` is bigger than before.
 	pub fn relax_max<const MAX2: usize>(self) -> BVec<T, MAX2> {
 		// assert that MAX2 >= MAX at compile time
-		_ = <const_checks::Pair<MAX2, MAX> as const_checks::AssertGe>::VALID;
+		let () = <const_checks::Pair<MAX2, MAX> as const_checks::AssertGe>::VALID;
 
 		unsafe { BVec::from_vec_unchecked(self.s) }
 	}
@@ -155,6 +187,21 @@ impl<T, const MAX: usize> BVec<T, MAX> {
 	pub fn clear(&mut self) {
 		self.s.clear()
 	}
+	/// Clones the contents of this vector into `target`, reusing its allocation where possible.
+	///
+	/// ```
+	/// # use maxlen::{bvec, BVec};
+	/// let v: BVec<u8, 8> = bvec![1, 2, 3];
+	/// let mut snapshot = BVec::with_capacity(8);
+	/// v.clone_into(&mut snapshot);
+	/// assert_eq!(snapshot, [1, 2, 3]);
+	/// ```
+	pub fn clone_into(&self, target: &mut BVec<T, MAX>)
+	where
+		T: Clone,
+	{
+		target.s.clone_from(&self.s)
+	}
 	/// Removes all but the first of consecutive elements in the vector satisfying a given equality relation.
 	///
 	/// See [`Vec::dedup_by`] for more information.
@@ -176,7 +223,7 @@ impl<T, const MAX: usize> BVec<T, MAX> {
 	/// Removes the subslice indicated by the given range from the vector, returning a double-ended iterator over the removed subslice.
 	///
 	/// See [`Vec::drain`] for more information.
-	pub fn drain<R: RangeBounds<usize>>(&mut self, range: R) -> std::vec::Drain<T> {
+	pub fn drain<R: RangeBounds<usize>>(&mut self, range: R) -> alloc::vec::Drain<'_, T> {
 		self.s.drain(range)
 	}
 	/// Creates an iterator which uses a closure to determine if element in the range should be removed.
@@ -186,18 +233,96 @@ impl<T, const MAX: usize> BVec<T, MAX> {
 		&mut self,
 		range: R,
 		filter: F,
-	) -> std::vec::ExtractIf<T, F>
+	) -> alloc::vec::ExtractIf<'_, T, F>
 	where
 		F: FnMut(&mut T) -> bool,
 	{
 		self.s.extract_if(range, filter)
 	}
+	/// Resizes the vector to exactly `MAX` elements, filling all of them with `value`.
+	///
+	/// Existing elements are overwritten too. Allocates space for `MAX` elements if needed.
+	///
+	/// ```
+	/// # use maxlen::{bvec, BVec};
+	/// let mut buf: BVec<u8, 4> = bvec![1, 2];
+	/// buf.fill_to_capacity(0);
+	/// assert_eq!(buf, [0, 0, 0, 0]);
+	/// ```
+	pub fn fill_to_capacity(&mut self, value: T)
+	where
+		T: Clone,
+	{
+		self.s.fill(value.clone());
+		self.s.resize(MAX, value);
+	}
+	/// Inserts all elements of a slice at position `index`, performing a runtime check.
+	///
+	/// The elements after `index` are shifted only once.
+	///
+	/// # Panics
+	///
+	/// Panics if `index > len`.
+	pub fn insert_slice(&mut self, index: usize, other: &[T]) -> Result<(), LengthExceeded>
+	where
+		T: Clone,
+	{
+		let len = self.s.len();
+		assert!(
+			index <= len,
+			"insertion index (is {index}) should be <= len (is {len})"
+		);
+
+		let length = len.saturating_add(other.len());
+		if length > MAX {
+			return Err(LengthExceeded {
+				length,
+				maximum: MAX,
+				encoding: LengthExceeded::ELEMENTS,
+			});
+		}
+
+		self.s.splice(index..index, other.iter().cloned());
+
+		Ok(())
+	}
 	/// Converts the vector into [`Box<BSlice<T, MAX>>`].
 	///
 	/// See [`Vec::into_boxed_slice`] for more information.
 	pub fn into_boxed_slice(self) -> Box<BSlice<T, MAX>> {
 		unsafe { Box::from_raw(Box::into_raw(self.s.into_boxed_slice()) as *mut BSlice<T, MAX>) }
 	}
+	/// Splits the vector into owned chunks of `CHUNK` elements, the last one possibly being shorter.
+	///
+	/// # Panics
+	///
+	/// Panics if `CHUNK` is 0.
+	///
+	/// ```
+	/// # use maxlen::{bvec, BVec};
+	/// let v: BVec<u32, 8> = bvec![1, 2, 3, 4, 5];
+	/// let chunks = v.into_chunks::<2>();
+	/// assert_eq!(chunks.len(), 3);
+	/// assert_eq!(chunks[0], [1, 2]);
+	/// assert_eq!(chunks[2], [5]);
+	/// ```
+	pub fn into_chunks<const CHUNK: usize>(self) -> Vec<BVec<T, CHUNK>> {
+		assert!(CHUNK != 0, "chunk size must be non-zero");
+
+		let mut chunks = Vec::with_capacity(self.s.len().div_ceil(CHUNK));
+		let mut iter = self.s.into_iter();
+		loop {
+			let chunk: Vec<T> = iter.by_ref().take(CHUNK).collect();
+			if chunk.is_empty() {
+				break;
+			}
+
+			// a chunk never has more than `CHUNK` elements
+			chunks.push(unsafe { BVec::from_vec_unchecked(chunk) });
+		}
+
+		chunks
+	}
 	/// Consumes and leaks the [`BVec`], returning a mutable reference to the contents, `&'a mut BSlice<T, MAX>`.
 	///
 	/// See [`Vec::leak`] for more information.
@@ -223,6 +348,26 @@ impl<T, const MAX: usize> BVec<T, MAX> {
 	pub fn pop_if(&mut self, f: impl FnOnce(&mut T) -> bool) -> Option<T> {
 		self.s.pop_if(f)
 	}
+	/// Appends an element if there is spare capacity and the vector is shorter than `MAX`,
+	/// otherwise the element is given back.
+	///
+	/// This never reallocates.
+	///
+	/// ```
+	/// # use maxlen::BVec;
+	/// let mut v = BVec::<u8, 2>::with_capacity(2);
+	/// assert_eq!(v.push_within_capacity(1), Ok(()));
+	/// assert_eq!(v.push_within_capacity(2), Ok(()));
+	/// assert_eq!(v.push_within_capacity(3), Err(3));
+	/// ```
+	pub fn push_within_capacity(&mut self, value: T) -> Result<(), T> {
+		if self.len() >= MAX || self.len() == self.capacity() {
+			return Err(value);
+		}
+
+		self.s.push(value);
+		Ok(())
+	}
 	/// Removes and returns the element at position index within the vector, shifting all elements after it to the left.
 	///
 	/// See [`Vec::remove`] for more information.
@@ -252,12 +397,78 @@ impl<T, const MAX: usize> BVec<T, MAX> {
 	pub fn retain(&mut self, f: impl FnMut(&T) -> bool) {
 		self.s.retain(f)
 	}
+	/// Retains only the elements specified by the predicate, returning how many were removed.
+	///
+	/// See [`Vec::retain`] for more information.
+	pub fn retain_count(&mut self, f: impl FnMut(&T) -> bool) -> usize {
+		let original_len = self.s.len();
+		self.s.retain(f);
+
+		original_len - self.s.len()
+	}
+	/// Retains only the elements specified by the predicate, unless fewer than `MIN` would remain.
+	///
+	/// The predicate is called exactly once for each element before anything is removed.
+	/// If fewer than `MIN` elements pass, the vector is left unchanged and an error
+	/// with the number of elements that would have remained is returned.
+	///
+	/// ```
+	/// # use maxlen::{bvec, BVec, LengthOutOfBounds};
+	/// let mut v: BVec<u32, 8> = bvec![1, 2, 3, 4, 5];
+	/// let err = v.retain_min::<2, _>(|x| *x > 4).unwrap_err();
+	/// assert!(matches!(err, LengthOutOfBounds::BelowMinimum { length: 1, minimum: 2 }));
+	/// assert_eq!(v, [1, 2, 3, 4, 5]);
+	/// v.retain_min::<2, _>(|x| *x > 3).unwrap();
+	/// assert_eq!(v, [4, 5]);
+	/// ```
+	pub fn retain_min<const MIN: usize, F: FnMut(&T) -> bool>(
+		&mut self,
+		f: F,
+	) -> Result<(), LengthOutOfBounds> {
+		if MIN == 0 {
+			// nothing can be rejected, so there is no need to remember the decisions
+			self.s.retain(f);
+			return Ok(());
+		}
+
+		let keep: Vec<bool> = self.s.iter().map(f).collect();
+		let remaining = keep.iter().filter(|&&k| k).count();
+		if remaining < MIN {
+			return Err(LengthOutOfBounds::BelowMinimum {
+				length: remaining,
+				minimum: MIN,
+			});
+		}
+
+		let mut keep = keep.into_iter();
+		self.s.retain(|_| keep.next().unwrap_or(true));
+
+		Ok(())
+	}
 	/// Retains only the elements specified by the predicate, passing a mutable reference to it.
 	///
 	/// See [`Vec::retain_mut`] for more information.
 	pub fn retain_mut(&mut self, f: impl FnMut(&mut T) -> bool) {
 		self.s.retain_mut(f)
 	}
+	/// Reverses the order of elements in the vector, in place.
+	///
+	/// See [`slice::reverse`] for more information.
+	pub fn reverse(&mut self) {
+		self.s.reverse()
+	}
+	/// Rotates the vector in-place such that the first `mid` elements move to the end.
+	///
+	/// See [`slice::rotate_left`] for more information.
+	pub fn rotate_left(&mut self, mid: usize) {
+		self.s.rotate_left(mid)
+	}
+	/// Rotates the vector in-place such that the last `k` elements move to the front.
+	///
+	/// See [`slice::rotate_right`] for more information.
+	pub fn rotate_right(&mut self, k: usize) {
+		self.s.rotate_right(k)
+	}
 	/// Shrinks the capacity of the vector with a lower bound.
 	///
 	/// See [`Vec::shrink_to`] for more information.
@@ -270,17 +481,35 @@ impl<T, const MAX: usize> BVec<T, MAX> {
 	pub fn shrink_to_fit(&mut self) {
 		self.s.shrink_to_fit()
 	}
+	/// Sorts the vector, preserving the initial order of equal elements.
+	///
+	/// See [`slice::sort`] for more information.
+	pub fn sort(&mut self)
+	where
+		T: Ord,
+	{
+		self.s.sort()
+	}
+	/// Sorts the vector without preserving the initial order of equal elements.
+	///
+	/// See [`slice::sort_unstable`] for more information.
+	pub fn sort_unstable(&mut self)
+	where
+		T: Ord,
+	{
+		self.s.sort_unstable()
+	}
 	/// Returns the remaining spare capacity of the vector as a slice of `MaybeUninit<T>`.
 	///
 	/// See [`Vec::shrink_to_fit`] for more information.
-	pub fn spare_capacity_mut(&mut self) -> &mut [std::mem::MaybeUninit<T>] {
+	pub fn spare_capacity_mut(&mut self) -> &mut [core::mem::MaybeUninit<T>] {
 		self.s.spare_capacity_mut()
 	}
 	/// Creates a splicing iterator that replaces the specified range in the vector with the
 	/// given replace_with iterator and yields the removed items. replace_with does not need to be the same length as range.
 	///
 	/// See [`Vec::splice`] for more information.
-	pub fn splice<R, I>(&mut self, range: R, replace_with: I) -> std::vec::Splice<I::IntoIter>
+	pub fn splice<R, I>(&mut self, range: R, replace_with: I) -> alloc::vec::Splice<'_, I::IntoIter>
 	where
 		R: RangeBounds<usize>,
 		I: IntoIterator<Item = T>,
@@ -293,18 +522,92 @@ impl<T, const MAX: usize> BVec<T, MAX> {
 	pub fn split_off(&mut self, idx: usize) -> BVec<T, MAX> {
 		unsafe { BVec::from_vec_unchecked(self.s.split_off(idx)) }
 	}
+	/// Swaps two elements in the vector.
+	///
+	/// See [`slice::swap`] for more information.
+	pub fn swap(&mut self, a: usize, b: usize) {
+		self.s.swap(a, b)
+	}
 	/// Removes an element from the vector and returns it.
 	///
 	/// See [`Vec::swap_remove`] for more information.
 	pub fn swap_remove(&mut self, idx: usize) -> T {
 		self.s.swap_remove(idx)
 	}
+	/// Takes all the elements out, leaving an empty vector in place.
+	///
+	/// The returned vector keeps the allocation, while `self` is left without one.
+	///
+	/// ```
+	/// # use maxlen::{bvec, BVec};
+	/// let mut batch: BVec<u32, 4> = bvec![1, 2, 3];
+	/// let flushed = batch.take_all();
+	/// assert_eq!(flushed, [1, 2, 3]);
+	/// assert!(batch.is_empty());
+	/// ```
+	pub fn take_all(&mut self) -> BVec<T, MAX> {
+		unsafe { Self::from_vec_unchecked(core::mem::take(&mut self.s)) }
+	}
 	/// Shortens the vector, keeping the first len elements and dropping the rest.
 	///
 	/// See [`Vec::truncate`] for more information.
 	pub fn truncate(&mut self, len: usize) {
 		self.s.truncate(len)
 	}
+	/// Shortens the vector, keeping the first `len` elements and returning the rest.
+	///
+	/// Like [`truncate`][Self::truncate], this has no effect and returns an empty vector
+	/// if `len` is greater than or equal to the vector's current length.
+	///
+	/// ```
+	/// # use maxlen::{bvec, BVec};
+	/// let mut v: BVec<u8, 8> = bvec![1, 2, 3, 4];
+	/// let tail = v.truncate_off(1);
+	/// assert_eq!(v, [1]);
+	/// assert_eq!(tail, [2, 3, 4]);
+	/// ```
+	pub fn truncate_off(&mut self, len: usize) -> BVec<T, MAX> {
+		if len >= self.len() {
+			return Self::new();
+		}
+
+		// the removed tail is never longer than the original
+		unsafe { Self::from_vec_unchecked(self.s.split_off(len)) }
+	}
+	/// Collects an iterator into a [`BVec`], stopping with an error at the first element that doesn't fit.
+	///
+	/// Capacity is reserved according to the iterator's lower size hint, clamped to `MAX`.
+	///
+	/// On overflow, one element past `MAX` is consumed from the iterator (and dropped
+	/// along with the collected ones). The rest of the iterator is not consumed, so the
+	/// reported length is only a lower bound: it is always `MAX + 1` (saturating at `usize::MAX`).
+	///
+	/// ```
+	/// # use maxlen::BVec;
+	/// let v = BVec::<u32, 4>::try_from_iter((1..).take(4)).unwrap();
+	/// assert_eq!(v, [1, 2, 3, 4]);
+	///
+	/// let mut iter = 1..10;
+	/// assert!(BVec::<u32, 4>::try_from_iter(&mut iter).is_err());
+	/// // 5 elements were taken from the iterator
+	/// assert_eq!(iter.next(), Some(6));
+	/// ```
+	pub fn try_from_iter<I: IntoIterator<Item = T>>(iter: I) -> Result<Self, LengthExceeded> {
+		let iter = iter.into_iter();
+		let mut s = Vec::with_capacity(iter.size_hint().0.min(MAX));
+		for item in iter {
+			if s.len() == MAX {
+				return Err(LengthExceeded {
+					length: MAX.saturating_add(1),
+					maximum: MAX,
+					encoding: LengthExceeded::ELEMENTS,
+				});
+			}
+			s.push(item);
+		}
+
+		Ok(unsafe { Self::from_vec_unchecked(s) })
+	}
 	/// Tries to reserve capacity for at least `additional` more elements to be inserted in the given [`BVec<T>`].
 	/// The collection may reserve more space to speculatively avoid frequent reallocations.
 	/// After calling `try_reserve`, capacity will be greater than or equal to `self.len() + additional` if it
@@ -314,7 +617,7 @@ impl<T, const MAX: usize> BVec<T, MAX> {
 	pub fn try_reserve(
 		&mut self,
 		additional: usize,
-	) -> Result<(), std::collections::TryReserveError> {
+	) -> Result<(), alloc::collections::TryReserveError> {
 		self.s.try_reserve(additional)
 	}
 	/// Tries to reserve the minimum capacity for at least `additional` elements to be inserted in the given `BVec<T>`.
@@ -326,7 +629,7 @@ impl<T, const MAX: usize> BVec<T, MAX> {
 	pub fn try_reserve_exact(
 		&mut self,
 		additional: usize,
-	) -> Result<(), std::collections::TryReserveError> {
+	) -> Result<(), alloc::collections::TryReserveError> {
 		self.s.try_reserve_exact(additional)
 	}
 	/// Constructs a new, empty [`BVec<T>`] with at least the specified capacity.
@@ -345,6 +648,115 @@ impl<T: PartialEq, const MAX: usize> BVec<T, MAX> {
 	pub fn dedup(&mut self) {
 		self.s.dedup()
 	}
+	/// Returns a copy of the vector with consecutive repeated elements removed,
+	/// leaving the original untouched.
+	///
+	/// See [`Vec::dedup`] for more information.
+	pub fn deduped(&self) -> BVec<T, MAX>
+	where
+		T: Clone,
+	{
+		let mut s = Vec::with_capacity(self.s.len());
+		for x in &self.s {
+			if s.last() != Some(x) {
+				s.push(x.clone());
+			}
+		}
+
+		// never longer than the original
+		unsafe { BVec::from_vec_unchecked(s) }
+	}
+}
+impl<T, const N: usize, const MAX: usize> BVec<[T; N], MAX> {
+	/// Takes a `BVec<[T; N], MAX>` and flattens it into a `BVec<T, OUT>`, performing a runtime check.
+	///
+	/// Never fails if `MAX * N <= OUT`.
+	///
+	/// See [`Vec::into_flattened`] for more information.
+	pub fn into_flattened<const OUT: usize>(self) -> Result<BVec<T, OUT>, LengthExceeded> {
+		let length = self.s.len().saturating_mul(N);
+		if length > OUT {
+			return Err(LengthExceeded {
+				length,
+				maximum: OUT,
+				encoding: LengthExceeded::ELEMENTS,
+			});
+		}
+
+		Ok(unsafe { BVec::from_vec_unchecked(self.s.into_flattened()) })
+	}
+}
+
+impl<const MAX: usize> BVec<u8, MAX> {
+	/// Returns the worst-case length of this type serialized with a varint length prefix,
+	/// as done by [`postcard`](https://docs.rs/postcard).
+	///
+	/// This is the size of the prefix for a length of `MAX`, plus `MAX` bytes.
+	///
+	/// ```
+	/// # use maxlen::BVec;
+	/// const BUF: usize = BVec::<u8, 200>::max_serialized_len();
+	/// assert_eq!(BUF, 202);
+	/// ```
+	pub const fn max_serialized_len() -> usize {
+		varint_len(MAX) + MAX
+	}
+}
+
+/// Number of bytes a LEB128 varint takes to encode `n`.
+const fn varint_len(n: usize) -> usize {
+	let bits = usize::BITS - n.leading_zeros();
+	if bits == 0 {
+		1
+	} else {
+		bits.div_ceil(7) as usize
+	}
+}
+
+impl<const MAX: usize> BVec<NonZero<u8>, MAX> {
+	/// Creates a `BVec<NonZero<u8>, MAX>` from the bytes of a [`CStr`], performing a runtime check.
+	///
+	/// The nul terminator is not included and does not count towards `MAX`. An empty
+	/// `CStr` is rejected with [`LengthOutOfBounds::BelowMinimum`], and interior nul
+	/// bytes can't occur by construction.
+	///
+	/// ```
+	/// # use maxlen::BVec;
+	/// # use core::num::NonZero;
+	/// let v = BVec::<NonZero<u8>, 5>::from_cstr(c"hello").unwrap();
+	/// assert_eq!(v.len(), 5);
+	/// assert_eq!(v.to_cstring().as_c_str(), c"hello");
+	/// assert!(BVec::<NonZero<u8>, 5>::from_cstr(c"").is_err());
+	/// ```
+	pub fn from_cstr(s: &CStr) -> Result<Self, LengthOutOfBounds> {
+		let bytes = s.to_bytes();
+		if bytes.is_empty() {
+			return Err(LengthOutOfBounds::BelowMinimum {
+				length: 0,
+				minimum: 1,
+			});
+		}
+		if bytes.len() > MAX {
+			return Err(LengthExceeded {
+				length: bytes.len(),
+				maximum: MAX,
+				encoding: LengthExceeded::ELEMENTS,
+			}
+			.into());
+		}
+
+		// CStr never contains a nul byte before its terminator
+		let v = bytes
+			.iter()
+			.map(|&b| unsafe { NonZero::new_unchecked(b) })
+			.collect();
+
+		Ok(unsafe { Self::from_vec_unchecked(v) })
+	}
+	/// Copies the bytes into a new [`CString`], appending the nul terminator.
+	pub fn to_cstring(&self) -> CString {
+		self.s.clone().into()
+	}
 }
 
 // Trait implementations relating BSlice and BVec
@@ -453,6 +865,9 @@ impl<T: Clone, const MAX: usize> Clone for BVec<T, MAX> {
 	fn clone(&self) -> Self {
 		Self { s: self.s.clone() }
 	}
+	fn clone_from(&mut self, source: &Self) {
+		self.s.clone_from(&source.s)
+	}
 }
 impl<'a, T: Clone, const MAX: usize> From<&'a BVec<T, MAX>> for Cow<'a, BSlice<T, MAX>> {
 	fn from(value: &'a BVec<T, MAX>) -> Self {
@@ -464,8 +879,8 @@ impl<T: Clone, const MAX: usize> From<BVec<T, MAX>> for Cow<'_, BSlice<T, MAX>>
 		Self::Owned(value)
 	}
 }
-impl<const MAX: usize> From<BVec<std::num::NonZero<u8>, MAX>> for std::ffi::CString {
-	fn from(value: BVec<std::num::NonZero<u8>, MAX>) -> Self {
+impl<const MAX: usize> From<BVec<NonZero<u8>, MAX>> for CString {
+	fn from(value: BVec<NonZero<u8>, MAX>) -> Self {
 		value.s.into()
 	}
 }
@@ -474,7 +889,7 @@ impl<T, const MAX: usize> From<BVec<T, MAX>> for Arc<BSlice<T, MAX>> {
 		unsafe { Arc::from_raw(Arc::into_raw(Arc::<[T]>::from(value.s)) as *const BSlice<T, MAX>) }
 	}
 }
-impl<T: Ord, const MAX: usize> From<BVec<T, MAX>> for std::collections::BinaryHeap<T> {
+impl<T: Ord, const MAX: usize> From<BVec<T, MAX>> for alloc::collections::BinaryHeap<T> {
 	fn from(value: BVec<T, MAX>) -> Self {
 		value.s.into()
 	}
@@ -484,7 +899,7 @@ impl<T, const MAX: usize> From<BVec<T, MAX>> for Rc<BSlice<T, MAX>> {
 		unsafe { Rc::from_raw(Rc::into_raw(Rc::<[T]>::from(value.s)) as *const BSlice<T, MAX>) }
 	}
 }
-impl<T, const MAX: usize> From<BVec<T, MAX>> for std::collections::VecDeque<T> {
+impl<T, const MAX: usize> From<BVec<T, MAX>> for VecDeque<T> {
 	fn from(value: BVec<T, MAX>) -> Self {
 		value.s.into()
 	}
@@ -502,7 +917,7 @@ impl<'a, T, const MAX: usize> IntoIterator for &'a BVec<T, MAX> {
 	type IntoIter = <&'a Vec<T> as IntoIterator>::IntoIter;
 
 	fn into_iter(self) -> Self::IntoIter {
-		(&self.s).into_iter()
+		self.s.iter()
 	}
 }
 impl<'a, T, const MAX: usize> IntoIterator for &'a mut BVec<T, MAX> {
@@ -510,7 +925,7 @@ impl<'a, T, const MAX: usize> IntoIterator for &'a mut BVec<T, MAX> {
 	type IntoIter = <&'a mut Vec<T> as IntoIterator>::IntoIter;
 
 	fn into_iter(self) -> Self::IntoIter {
-		(&mut self.s).into_iter()
+		self.s.iter_mut()
 	}
 }
 impl<T: PartialEq<U>, U, const MAX1: usize, const MAX2: usize> PartialEq<BVec<U, MAX2>>
@@ -624,6 +1039,16 @@ impl<T, U: PartialEq<T>, const MAX: usize> PartialEq<&mut BVec<T, MAX>> for Vec<
 		self.eq(&**other)
 	}
 }
+impl<T: PartialEq<U>, U, const MAX: usize> PartialEq<VecDeque<U>> for BVec<T, MAX> {
+	fn eq(&self, other: &VecDeque<U>) -> bool {
+		(**self).eq(other)
+	}
+}
+impl<T, U: PartialEq<T>, const MAX: usize> PartialEq<BVec<T, MAX>> for VecDeque<U> {
+	fn eq(&self, other: &BVec<T, MAX>) -> bool {
+		self.eq(&**other)
+	}
+}
 impl<T: PartialEq<U>, U: Clone, const MAX: usize> PartialEq<Cow<'_, [U]>> for BVec<T, MAX> {
 	fn eq(&self, other: &Cow<'_, [U]>) -> bool {
 		(**self).eq(&**other)
@@ -700,12 +1125,22 @@ impl<T: Eq, const MAX: usize> Eq for BVec<T, MAX> {}
 impl<T: PartialOrd, const MAX: usize, const MAX2: usize> PartialOrd<BVec<T, MAX2>>
 	for BVec<T, MAX>
 {
-	fn partial_cmp(&self, other: &BVec<T, MAX2>) -> Option<std::cmp::Ordering> {
+	fn partial_cmp(&self, other: &BVec<T, MAX2>) -> Option<core::cmp::Ordering> {
+		(**self).partial_cmp(&**other)
+	}
+}
+impl<T: PartialOrd, const MAX: usize> PartialOrd<VecDeque<T>> for BVec<T, MAX> {
+	fn partial_cmp(&self, other: &VecDeque<T>) -> Option<core::cmp::Ordering> {
 		(**self).partial_cmp(other)
 	}
 }
+impl<T: PartialOrd, const MAX: usize> PartialOrd<BVec<T, MAX>> for VecDeque<T> {
+	fn partial_cmp(&self, other: &BVec<T, MAX>) -> Option<core::cmp::Ordering> {
+		self.partial_cmp(&**other)
+	}
+}
 impl<T: Ord, const MAX: usize> Ord for BVec<T, MAX> {
-	fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+	fn cmp(&self, other: &Self) -> core::cmp::Ordering {
 		(**self).cmp(other)
 	}
 }
@@ -745,21 +1180,53 @@ impl<T, const MAX: usize, const N: usize> TryFrom<BVec<T, MAX>> for Box<[T; N]>
 	}
 }
 impl<const MAX: usize> TryFrom<BVec<u8, MAX>> for String {
-	type Error = std::string::FromUtf8Error;
+	type Error = alloc::string::FromUtf8Error;
 
 	fn try_from(value: BVec<u8, MAX>) -> Result<Self, Self::Error> {
 		String::try_from(value.s)
 	}
 }
+impl<const MAX: usize> TryFrom<String> for BVec<u8, MAX> {
+	type Error = LengthExceeded;
+
+	fn try_from(value: String) -> Result<Self, Self::Error> {
+		Self::from_vec(value.into_bytes())
+	}
+}
+/// Reads bytes from the front of the vector, removing them.
+///
+/// Like the [`Read`](std::io::Read) implementation of [`VecDeque<u8>`], this drains the
+/// vector instead of keeping a cursor, so every read shifts the remaining bytes.
+/// Use [`BCursor`](crate::BCursor) to read without consuming.
+///
+/// ```
+/// # use maxlen::{bvec, BVec};
+/// # use std::io::Read;
+/// let mut pipe: BVec<u8, 8> = bvec![1, 2, 3];
+/// let mut buf = [0; 2];
+/// assert_eq!(pipe.read(&mut buf).unwrap(), 2);
+/// assert_eq!(buf, [1, 2]);
+/// assert_eq!(pipe, [3]);
+/// ```
+#[cfg(feature = "std")]
+impl<const MAX: usize> std::io::Read for BVec<u8, MAX> {
+	fn read(&mut self, buf: &mut [u8]) -> std::io::Result<usize> {
+		let n = buf.len().min(self.s.len());
+		buf[..n].copy_from_slice(&self.s[..n]);
+		self.s.drain(..n);
+
+		Ok(n)
+	}
+}
 
 #[cfg(feature = "serde")]
 mod serde_impls {
-	use std::marker::PhantomData;
+	use core::marker::PhantomData;
 
 	use super::*;
 	use serde::{
 		Deserialize, Serialize,
-		de::{SeqAccess, Visitor},
+		de::{IntoDeserializer, SeqAccess, Visitor},
 		ser::SerializeSeq,
 	};
 
@@ -780,8 +1247,28 @@ mod serde_impls {
 	impl<'de, T: Deserialize<'de>, const MAX: usize> Visitor<'de> for BVecVisitor<T, MAX> {
 		type Value = BVec<T, MAX>;
 
-		fn expecting(&self, formatter: &mut std::fmt::Formatter) -> std::fmt::Result {
-			formatter.write_str("a sequence")
+		fn expecting(&self, formatter: &mut core::fmt::Formatter) -> core::fmt::Result {
+			formatter.write_str("a sequence or bytes")
+		}
+		// formats with a native byte string type give bytes instead of a sequence,
+		// each byte is then deserialized as an element
+		fn visit_bytes<ER>(self, v: &[u8]) -> Result<Self::Value, ER>
+		where
+			ER: serde::de::Error,
+		{
+			if v.len() > MAX {
+				return Err(serde::de::Error::invalid_length(
+					v.len(),
+					&alloc::format!("{MAX}").as_str(),
+				));
+			}
+
+			let vec = v
+				.iter()
+				.map(|&b| T::deserialize(b.into_deserializer()))
+				.collect::<Result<Vec<T>, ER>>()?;
+
+			Ok(unsafe { BVec::from_vec_unchecked(vec) })
 		}
 		fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
 		where
@@ -797,7 +1284,7 @@ mod serde_impls {
 				Ok(bvec) => Ok(bvec),
 				Err(_e) => Err(serde::de::Error::invalid_length(
 					len,
-					&format!("{MAX}").as_str(),
+					&alloc::format!("{MAX}").as_str(),
 				)),
 			}
 		}
@@ -812,9 +1299,309 @@ mod serde_impls {
 	}
 }
 
+#[cfg(feature = "rkyv")]
+pub use rkyv_impls::ArchivedBVec;
+
+#[cfg(feature = "rkyv")]
+mod rkyv_impls {
+	use super::*;
+	use rkyv::{
+		Archive, Deserialize, Place, Portable, Serialize,
+		bytecheck::CheckBytes,
+		rancor::{Fallible, Source},
+		ser::{Allocator, Writer},
+		vec::{ArchivedVec, VecResolver},
+	};
+
+	/// An archived [`BVec`], which can also be deserialized into a [`Box<BSlice>`].
+	///
+	/// Validation (`check_bytes`) rejects archives longer than `MAX` elements.
+	///
+	/// A [`BSlice`] is archived as a `BSlice` of the archived elements, so that `Box<BSlice>`
+	/// and other pointers to it archive through rkyv's own impls, with the same check.
+	#[derive(Debug)]
+	#[repr(transparent)]
+	pub struct ArchivedBVec<T, const MAX: usize> {
+		v: ArchivedVec<T>,
+	}
+
+	// SAFETY: `ArchivedBVec` is `repr(transparent)` over a portable type.
+	unsafe impl<T: Portable, const MAX: usize> Portable for ArchivedBVec<T, MAX> {}
+
+	// SAFETY: the length is checked against `MAX` before the inner vector (and its contents) is checked.
+	unsafe impl<T, C, const MAX: usize> CheckBytes<C> for ArchivedBVec<T, MAX>
+	where
+		ArchivedVec<T>: CheckBytes<C>,
+		C: Fallible + ?Sized,
+		C::Error: Source,
+	{
+		unsafe fn check_bytes(value: *const Self, context: &mut C) -> Result<(), C::Error> {
+			let length = unsafe { (*value).v.len() };
+			if length > MAX {
+				return Err(C::Error::new(LengthExceeded {
+					length,
+					maximum: MAX,
+					encoding: LengthExceeded::ELEMENTS,
+				}));
+			}
+
+			unsafe { ArchivedVec::<T>::check_bytes(value.cast(), context) }
+		}
+	}
+
+	impl<T, const MAX: usize> Deref for ArchivedBVec<T, MAX> {
+		type Target = BSlice<T, MAX>;
+
+		fn deref(&self) -> &Self::Target {
+			unsafe { BSlice::from_slice_unchecked(self.v.as_slice()) }
+		}
+	}
+
+	impl<T: Archive, const MAX: usize> Archive for BVec<T, MAX> {
+		type Archived = ArchivedBVec<T::Archived, MAX>;
+		type Resolver = VecResolver;
+
+		fn resolve(&self, resolver: Self::Resolver, out: Place<Self::Archived>) {
+			let out = unsafe { out.cast_unchecked::<ArchivedVec<T::Archived>>() };
+			ArchivedVec::resolve_from_slice(&self.s, resolver, out);
+		}
+	}
+	impl<T: Serialize<S>, S: Fallible + Allocator + Writer + ?Sized, const MAX: usize> Serialize<S>
+		for BVec<T, MAX>
+	{
+		fn serialize(&self, serializer: &mut S) -> Result<Self::Resolver, S::Error> {
+			ArchivedVec::<T::Archived>::serialize_from_slice(&self.s, serializer)
+		}
+	}
+	impl<T: Archive, D: Fallible + ?Sized, const MAX: usize> Deserialize<BVec<T, MAX>, D>
+		for ArchivedBVec<T::Archived, MAX>
+	where
+		ArchivedVec<T::Archived>: Deserialize<Vec<T>, D>,
+		D::Error: Source,
+	{
+		fn deserialize(&self, deserializer: &mut D) -> Result<BVec<T, MAX>, D::Error> {
+			// checked again, since the archive may have been accessed without validation
+			BVec::from_vec(self.v.deserialize(deserializer)?).map_err(D::Error::new)
+		}
+	}
+
+	impl<T: Archive, D: Fallible + ?Sized, const MAX: usize> Deserialize<Box<BSlice<T, MAX>>, D>
+		for ArchivedBVec<T::Archived, MAX>
+	where
+		ArchivedVec<T::Archived>: Deserialize<Vec<T>, D>,
+		D::Error: Source,
+	{
+		fn deserialize(&self, deserializer: &mut D) -> Result<Box<BSlice<T, MAX>>, D::Error> {
+			Deserialize::<BVec<T, MAX>, D>::deserialize(self, deserializer)
+				.map(BVec::into_boxed_slice)
+		}
+	}
+}
+
+#[cfg(feature = "borsh")]
+mod borsh_impls {
+	use super::*;
+	use borsh::{
+		BorshDeserialize, BorshSerialize,
+		io::{Error, ErrorKind, Read, Write},
+	};
+
+	impl<T: BorshSerialize, const MAX: usize> BorshSerialize for BVec<T, MAX> {
+		fn serialize<W: Write>(&self, writer: &mut W) -> borsh::io::Result<()> {
+			self.s.serialize(writer)
+		}
+	}
+	impl<T: BorshDeserialize, const MAX: usize> BorshDeserialize for BVec<T, MAX> {
+		fn deserialize_reader<R: Read>(reader: &mut R) -> borsh::io::Result<Self> {
+			// the length prefix is checked before anything gets allocated
+			let prefix = u32::deserialize_reader(reader)?;
+			let length = prefix as usize;
+			if length > MAX {
+				return Err(Error::new(
+					ErrorKind::InvalidData,
+					LengthExceeded {
+						length,
+						maximum: MAX,
+						encoding: LengthExceeded::ELEMENTS,
+					},
+				));
+			}
+
+			let s = Vec::deserialize_reader(&mut (&prefix.to_le_bytes()[..]).chain(reader))?;
+
+			Ok(unsafe { Self::from_vec_unchecked(s) })
+		}
+	}
+}
+
+#[cfg(feature = "speedy")]
+mod speedy_impls {
+	use super::*;
+	use speedy::{Context, Readable, Reader, Writable, Writer};
+
+	impl<C: Context, T: Writable<C>, const MAX: usize> Writable<C> for BVec<T, MAX> {
+		fn write_to<W: ?Sized + Writer<C>>(&self, writer: &mut W) -> Result<(), C::Error> {
+			self.s.write_to(writer)
+		}
+		fn bytes_needed(&self) -> Result<usize, C::Error> {
+			Writable::<C>::bytes_needed(&self.s)
+		}
+	}
+	impl<'a, C: Context, T: Readable<'a, C>, const MAX: usize> Readable<'a, C> for BVec<T, MAX> {
+		fn read_from<R: Reader<'a, C>>(reader: &mut R) -> Result<Self, C::Error> {
+			// the length prefix is checked before anything gets allocated
+			let length = reader.read_u32()? as usize;
+			if length > MAX {
+				return Err(speedy::Error::custom(LengthExceeded {
+					length,
+					maximum: MAX,
+					encoding: LengthExceeded::ELEMENTS,
+				})
+				.into());
+			}
+
+			let s = reader.read_vec(length)?;
+
+			Ok(unsafe { Self::from_vec_unchecked(s) })
+		}
+		fn minimum_bytes_needed() -> usize {
+			<Vec<T> as Readable<'a, C>>::minimum_bytes_needed()
+		}
+	}
+}
+
+#[cfg(feature = "arbitrary")]
+mod arbitrary_impls {
+	use super::*;
+	use arbitrary::{Arbitrary, Result, Unstructured};
+
+	impl<'a, T: Arbitrary<'a>, const MAX: usize> Arbitrary<'a> for BVec<T, MAX> {
+		fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+			// the length is chosen uniformly, but we stop early if the data runs out
+			let length = u.int_in_range(0..=MAX)?;
+
+			let mut s = Vec::new();
+			while s.len() < length && !u.is_empty() {
+				s.push(T::arbitrary(u)?);
+			}
+
+			Ok(unsafe { Self::from_vec_unchecked(s) })
+		}
+	}
+	impl<'a, T: Arbitrary<'a>, const MAX: usize> Arbitrary<'a> for Box<BSlice<T, MAX>> {
+		fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+			BVec::arbitrary(u).map(BVec::into_boxed_slice)
+		}
+	}
+}
+
+#[cfg(feature = "rand")]
+mod rand_impls {
+	use super::*;
+	use rand::{
+		Rng,
+		distr::{Distribution, StandardUniform},
+	};
+
+	impl<T, const MAX: usize> Distribution<BVec<T, MAX>> for StandardUniform
+	where
+		StandardUniform: Distribution<T>,
+	{
+		fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> BVec<T, MAX> {
+			let length = rng.random_range(0..=MAX);
+			let s = (0..length).map(|_| rng.random()).collect();
+
+			unsafe { BVec::from_vec_unchecked(s) }
+		}
+	}
+}
+
+#[cfg(feature = "valuable")]
+mod valuable_impls {
+	use super::*;
+	use valuable::{Valuable, Value, Visit};
+
+	impl<T: Valuable, const MAX: usize> Valuable for BVec<T, MAX> {
+		fn as_value(&self) -> Value<'_> {
+			self.s.as_value()
+		}
+		fn visit(&self, visit: &mut dyn Visit) {
+			self.s.visit(visit)
+		}
+	}
+}
+
+#[cfg(feature = "smallvec")]
+mod smallvec_impls {
+	use super::*;
+	use smallvec::{Array, SmallVec};
+
+	// Both conversions go through `Vec`, which reuses the heap allocation
+	// if the `SmallVec` has spilled (and vice versa if the `Vec` doesn't fit inline).
+	impl<A: Array, const MAX: usize> TryFrom<SmallVec<A>> for BVec<A::Item, MAX> {
+		type Error = LengthExceeded;
+
+		fn try_from(value: SmallVec<A>) -> Result<Self, Self::Error> {
+			BSlice::<A::Item, MAX>::from_slice(&value)?;
+
+			Ok(unsafe { Self::from_vec_unchecked(value.into_vec()) })
+		}
+	}
+	impl<A: Array, const MAX: usize> From<BVec<A::Item, MAX>> for SmallVec<A> {
+		fn from(value: BVec<A::Item, MAX>) -> Self {
+			SmallVec::from_vec(value.s)
+		}
+	}
+}
+
+#[cfg(feature = "sqlx")]
+mod sqlx_impls {
+	use super::*;
+	use sqlx::{Database, Decode, Encode, Type, encode::IsNull, error::BoxDynError};
+
+	// Only byte vectors, since that's the only kind of `Vec` the drivers support.
+	impl<DB: Database, const MAX: usize> Type<DB> for BVec<u8, MAX>
+	where
+		Vec<u8>: Type<DB>,
+	{
+		fn type_info() -> DB::TypeInfo {
+			<Vec<u8> as Type<DB>>::type_info()
+		}
+		fn compatible(ty: &DB::TypeInfo) -> bool {
+			<Vec<u8> as Type<DB>>::compatible(ty)
+		}
+	}
+	impl<'q, DB: Database, const MAX: usize> Encode<'q, DB> for BVec<u8, MAX>
+	where
+		Vec<u8>: Encode<'q, DB>,
+	{
+		fn encode(self, buf: &mut DB::ArgumentBuffer<'q>) -> Result<IsNull, BoxDynError> {
+			self.s.encode(buf)
+		}
+		fn encode_by_ref(&self, buf: &mut DB::ArgumentBuffer<'q>) -> Result<IsNull, BoxDynError> {
+			self.s.encode_by_ref(buf)
+		}
+		fn produces(&self) -> Option<DB::TypeInfo> {
+			self.s.produces()
+		}
+		fn size_hint(&self) -> usize {
+			self.s.size_hint()
+		}
+	}
+	impl<'r, DB: Database, const MAX: usize> Decode<'r, DB> for BVec<u8, MAX>
+	where
+		Vec<u8>: Decode<'r, DB>,
+	{
+		fn decode(value: DB::ValueRef<'r>) -> Result<Self, BoxDynError> {
+			Ok(BVec::from_vec(Vec::decode(value)?)?)
+		}
+	}
+}
+
 #[cfg(test)]
 mod tests {
 	use crate::*;
+	use alloc::{collections::VecDeque, string::String, vec::Vec};
 
 	#[test]
 	fn test_bvec_macro() {
@@ -824,4 +1611,502 @@ mod tests {
 		let _: BVec<_, 255> = bvec![0, 1, 2, 3, 4];
 		// let _: BVec<_, 3> = bvec![0, 1, 2, 3, 4]; // should fail
 	}
+
+	#[test]
+	fn test_from_elem() {
+		let n = 3;
+		let v = BVec::<u8, 4>::from_elem(7, n).unwrap();
+		assert_eq!(v, [7; 3]);
+		assert_eq!(BVec::<u8, 4>::from_elem(7, n + 1).unwrap(), [7; 4]);
+		assert!(BVec::<u8, 4>::from_elem(7, 0).unwrap().is_empty());
+
+		let err = BVec::<u8, 4>::from_elem(7, n + 2).unwrap_err();
+		assert_eq!(err.length, 5);
+		assert_eq!(err.maximum, 4);
+	}
+
+	#[test]
+	fn test_into_flattened() {
+		let v: BVec<[u8; 4], 3> = bvec![[1, 2, 3, 4], [5, 6, 7, 8], [9, 10, 11, 12]];
+		let flat = v.clone().into_flattened::<12>().unwrap();
+		assert_eq!(flat, [1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12]);
+		let err = v.into_flattened::<8>().unwrap_err();
+		assert_eq!(err.length, 12);
+	}
+
+	#[cfg(feature = "std")]
+	#[test]
+	fn test_read() {
+		use alloc::vec::Vec;
+		use std::io::Read;
+
+		let mut v: BVec<u8, 8> = bvec![1, 2, 3, 4, 5, 6, 7];
+		let mut buf = [0; 3];
+		assert_eq!(v.read(&mut buf).unwrap(), 3);
+		assert_eq!(buf, [1, 2, 3]);
+		let reader: &mut dyn Read = &mut v;
+		assert_eq!(reader.read(&mut buf).unwrap(), 3);
+		assert_eq!(buf, [4, 5, 6]);
+		assert_eq!(v.read(&mut buf).unwrap(), 1);
+		assert_eq!(buf[0], 7);
+		assert_eq!(v.read(&mut buf).unwrap(), 0);
+		assert!(v.is_empty());
+
+		// written to and read from like a pipe
+		v.insert_slice(0, &[8, 9]).unwrap();
+		let mut out = Vec::new();
+		v.read_to_end(&mut out).unwrap();
+		assert_eq!(out, [8, 9]);
+		assert!(v.is_empty());
+	}
+
+	#[test]
+	fn test_mutators() {
+		let mut v: BVec<u8, 8> = bvec![3, 1, 4, 1, 5];
+		v.swap(0, 4);
+		assert_eq!(v, [5, 1, 4, 1, 3]);
+		v.reverse();
+		assert_eq!(v, [3, 1, 4, 1, 5]);
+		v.rotate_left(2);
+		assert_eq!(v, [4, 1, 5, 3, 1]);
+		v.rotate_right(1);
+		assert_eq!(v, [1, 4, 1, 5, 3]);
+		v.sort();
+		assert_eq!(v, [1, 1, 3, 4, 5]);
+		v.reverse();
+		v.sort_unstable();
+		assert_eq!(v, [1, 1, 3, 4, 5]);
+	}
+
+	#[cfg(feature = "serde")]
+	#[test]
+	fn test_serde_bytes_or_seq() {
+		let from_json: BVec<u8, 4> = serde_json::from_str("[1, 2, 3]").unwrap();
+		// msgpack bin 8 with 3 bytes
+		let from_msgpack: BVec<u8, 4> = rmp_serde::from_slice(&[0xc4, 3, 1, 2, 3]).unwrap();
+		assert_eq!(from_json, [1, 2, 3]);
+		assert_eq!(from_msgpack, [1, 2, 3]);
+
+		// other element types accept bytes as long as they accept a u8
+		let wide: BVec<u32, 4> = rmp_serde::from_slice(&[0xc4, 3, 1, 2, 3]).unwrap();
+		assert_eq!(wide, [1, 2, 3]);
+		assert!(rmp_serde::from_slice::<BVec<bool, 4>>(&[0xc4, 1, 1]).is_err());
+
+		assert!(serde_json::from_str::<BVec<u8, 2>>("[1, 2, 3]").is_err());
+		assert!(rmp_serde::from_slice::<BVec<u8, 2>>(&[0xc4, 3, 1, 2, 3]).is_err());
+
+		// sequences still work with msgpack
+		let encoded = rmp_serde::to_vec(&from_json).unwrap();
+		assert_eq!(
+			rmp_serde::from_slice::<BVec<u8, 4>>(&encoded).unwrap(),
+			[1, 2, 3]
+		);
+	}
+
+	#[test]
+	fn test_try_from_string() {
+		let s = String::from("aą");
+		let ptr = s.as_ptr();
+		let v = BVec::<u8, 3>::try_from(s).unwrap();
+		assert_eq!(v, [b'a', 0xC4, 0x85]);
+		assert_eq!(v.as_ptr(), ptr);
+		assert_eq!(String::try_from(v).unwrap(), "aą");
+
+		let e = BVec::<u8, 2>::try_from(String::from("aą")).unwrap_err();
+		assert_eq!(e.length, 3);
+		assert_eq!(e.maximum, 2);
+	}
+
+	#[test]
+	fn test_truncate_off() {
+		let mut v: BVec<u8, 8> = bvec![1, 2, 3, 4, 5];
+		assert_eq!(v.truncate_off(2), [3, 4, 5]);
+		assert_eq!(v, [1, 2]);
+
+		// nothing to remove
+		assert!(v.truncate_off(2).is_empty());
+		assert!(v.truncate_off(10).is_empty());
+		assert_eq!(v, [1, 2]);
+
+		assert_eq!(v.truncate_off(0), [1, 2]);
+		assert!(v.is_empty());
+	}
+
+	#[test]
+	fn test_clone_into() {
+		let mut target = BVec::<u32, 8>::with_capacity(8);
+		let ptr = target.as_ptr();
+
+		let v: BVec<u32, 8> = bvec![1, 2, 3, 4, 5];
+		v.clone_into(&mut target);
+		assert_eq!(target, [1, 2, 3, 4, 5]);
+		let v: BVec<u32, 8> = bvec![6, 7];
+		v.clone_into(&mut target);
+		assert_eq!(target, [6, 7]);
+		target.clone_from(&v);
+		assert_eq!(target, [6, 7]);
+
+		assert_eq!(target.capacity(), 8);
+		assert_eq!(target.as_ptr(), ptr);
+	}
+
+	#[test]
+	fn test_retain_min() {
+		let mut v: BVec<u32, 8> = bvec![1, 2, 3, 4, 5, 6];
+		let mut calls = 0;
+		let err = v
+			.retain_min::<3, _>(|x| {
+				calls += 1;
+				x % 2 == 0 && *x > 2
+			})
+			.unwrap_err();
+		assert!(matches!(
+			err,
+			LengthOutOfBounds::BelowMinimum {
+				length: 2,
+				minimum: 3
+			}
+		));
+		assert_eq!(calls, 6);
+		assert_eq!(v, [1, 2, 3, 4, 5, 6]);
+
+		// exactly at the minimum
+		v.retain_min::<3, _>(|x| x % 2 == 0).unwrap();
+		assert_eq!(v, [2, 4, 6]);
+		v.retain_min::<0, _>(|_| false).unwrap();
+		assert!(v.is_empty());
+	}
+
+	#[test]
+	fn test_push_within_capacity() {
+		let mut v = BVec::<u32, 4>::with_capacity(4);
+		let ptr = v.as_ptr();
+		for i in 0..4 {
+			assert_eq!(v.push_within_capacity(i), Ok(()));
+		}
+		assert_eq!(v.push_within_capacity(4), Err(4));
+		assert_eq!(v, [0, 1, 2, 3]);
+		assert_eq!(v.as_ptr(), ptr);
+
+		// capacity beyond MAX is not used
+		let mut v = BVec::<u32, 1>::from_vec(Vec::with_capacity(8)).unwrap();
+		assert_eq!(v.push_within_capacity(0), Ok(()));
+		assert_eq!(v.push_within_capacity(1), Err(1));
+
+		// no spare capacity
+		let mut v = BVec::<u32, 4>::new();
+		assert_eq!(v.push_within_capacity(0), Err(0));
+	}
+
+	#[test]
+	fn test_take_all() {
+		let mut v: BVec<u8, 4> = bvec![1, 2, 3];
+		let ptr = v.as_ptr();
+
+		let taken = v.take_all();
+		assert_eq!(taken, [1, 2, 3]);
+		assert_eq!(taken.as_ptr(), ptr);
+		assert!(v.is_empty());
+		assert_eq!(v.capacity(), 0);
+
+		// the emptied vector can be reused
+		v.insert_slice(0, &[4, 5]).unwrap();
+		assert_eq!(v.take_all(), [4, 5]);
+		assert!(v.take_all().is_empty());
+	}
+
+	#[test]
+	fn test_fill_to_capacity() {
+		let mut v: BVec<u8, 5> = BVec::new();
+		v.fill_to_capacity(7);
+		assert_eq!(v, [7; 5]);
+		v.fill_to_capacity(1);
+		assert_eq!(v, [1; 5]);
+
+		let mut v: BVec<u8, 5> = bvec![1, 2, 3];
+		v.fill_to_capacity(0);
+		assert_eq!(v, [0; 5]);
+
+		let mut v: BVec<u8, 0> = BVec::new();
+		v.fill_to_capacity(1);
+		assert!(v.is_empty());
+	}
+
+	#[test]
+	fn test_try_from_iter() {
+		let v = BVec::<u8, 4>::try_from_iter([1, 2, 3, 4]).unwrap();
+		assert_eq!(v, [1, 2, 3, 4]);
+		assert_eq!(v.capacity(), 4);
+		assert!(BVec::<u8, 4>::try_from_iter([]).unwrap().is_empty());
+
+		let err = BVec::<u8, 4>::try_from_iter([1, 2, 3, 4, 5, 6]).unwrap_err();
+		assert_eq!(err.length, 5);
+		assert_eq!(err.maximum, 4);
+
+		// the reservation never goes beyond the bound
+		let v = BVec::<u8, 3>::try_from_iter((0..100).filter(|&x| x < 3)).unwrap();
+		assert_eq!(v, [0, 1, 2]);
+		let v = BVec::<u64, 8>::try_from_iter(core::iter::repeat_n(7, 2)).unwrap();
+		assert_eq!(v.capacity(), 2);
+
+		// an empty bound only accepts an empty iterator
+		assert!(BVec::<u8, 0>::try_from_iter([1]).is_err());
+	}
+
+	#[test]
+	fn test_into_chunks() {
+		let v: BVec<u8, 8> = bvec![1, 2, 3, 4, 5, 6];
+
+		let chunks = v.clone().into_chunks::<3>();
+		assert_eq!(chunks.len(), 2);
+		assert_eq!(chunks[0], [1, 2, 3]);
+		assert_eq!(chunks[1], [4, 5, 6]);
+
+		let chunks = v.clone().into_chunks::<4>();
+		assert_eq!(chunks.len(), 2);
+		assert_eq!(chunks[0], [1, 2, 3, 4]);
+		assert_eq!(chunks[1], [5, 6]);
+
+		let chunks = v.clone().into_chunks::<8>();
+		assert_eq!(chunks.len(), 1);
+		assert_eq!(chunks[0], v);
+		assert_eq!(v.into_chunks::<1>().len(), 6);
+
+		let empty: BVec<u8, 8> = BVec::new();
+		assert!(empty.into_chunks::<3>().is_empty());
+	}
+
+	#[test]
+	#[should_panic]
+	fn test_into_chunks_zero() {
+		let v: BVec<u8, 8> = bvec![1, 2, 3];
+		v.into_chunks::<0>();
+	}
+
+	#[cfg(feature = "rkyv")]
+	#[test]
+	fn test_rkyv() {
+		use alloc::{boxed::Box, string::ToString};
+		use rkyv::rancor::Error;
+
+		let bvec: BVec<u32, 8> = bvec![1, 2, 3, 4, 5, 6, 7, 8];
+		let mut bytes = rkyv::to_bytes::<Error>(&bvec).unwrap();
+
+		let archived = rkyv::access::<rkyv::Archived<BVec<u32, 8>>, Error>(&bytes).unwrap();
+		assert_eq!(**archived, [1, 2, 3, 4, 5, 6, 7, 8]);
+		let deserialized: BVec<u32, 8> = rkyv::deserialize::<_, Error>(archived).unwrap();
+		assert_eq!(deserialized, bvec);
+		let boxed: Box<BSlice<u32, 8>> = rkyv::deserialize::<_, Error>(archived).unwrap();
+		assert_eq!(*boxed, bvec);
+
+		// `Box<BSlice>` is archived through rkyv's own `Box` impl, checking the slice's bound
+		let boxed_bytes = rkyv::to_bytes::<Error>(&boxed).unwrap();
+		let archived =
+			rkyv::access::<rkyv::Archived<Box<BSlice<u32, 8>>>, Error>(&boxed_bytes).unwrap();
+		let deserialized: Box<BSlice<u32, 8>> = rkyv::deserialize::<_, Error>(archived).unwrap();
+		assert_eq!(deserialized, boxed);
+		let err =
+			rkyv::access::<rkyv::Archived<Box<BSlice<u32, 7>>>, Error>(&boxed_bytes).unwrap_err();
+		assert!(err.to_string().contains("exceeded"));
+
+		// the root `ArchivedVec` is at the end of the buffer, its length being the last 4 bytes
+		let len = bytes.len();
+		bytes[len - 4..].copy_from_slice(&9u32.to_le_bytes());
+		let err = rkyv::access::<rkyv::Archived<BVec<u32, 8>>, Error>(&bytes).unwrap_err();
+		assert!(err.to_string().contains("exceeded"));
+	}
+
+	#[cfg(feature = "borsh")]
+	#[test]
+	fn test_borsh() {
+		use alloc::string::ToString;
+
+		let bvec: BVec<u16, 4> = bvec![1, 2, 3, 4];
+		let bytes = borsh::to_vec(&bvec).unwrap();
+		assert_eq!(borsh::from_slice::<BVec<u16, 4>>(&bytes).unwrap(), bvec);
+		assert!(borsh::from_slice::<BVec<u16, 3>>(&bytes).is_err());
+
+		// a huge length prefix with no data must fail on the prefix, not while allocating/reading
+		let err = borsh::from_slice::<BVec<u8, 16>>(&u32::MAX.to_le_bytes()).unwrap_err();
+		assert_eq!(err.kind(), std::io::ErrorKind::InvalidData);
+		assert!(err.to_string().contains("exceeded"));
+	}
+
+	#[cfg(feature = "speedy")]
+	#[test]
+	fn test_speedy() {
+		use alloc::string::ToString;
+		use speedy::{Readable, Writable};
+
+		let bvec: BVec<u16, 4> = bvec![1, 2, 3, 4];
+		let bytes = bvec.write_to_vec().unwrap();
+		let bslice: &BSlice<u16, 4> = &bvec;
+		assert_eq!(bslice.write_to_vec().unwrap(), bytes);
+		assert_eq!(BVec::<u16, 4>::read_from_buffer(&bytes).unwrap(), bvec);
+		assert!(BVec::<u16, 3>::read_from_buffer(&bytes).is_err());
+
+		// a huge length prefix with no data must fail on the prefix, not while allocating/reading
+		let err = BVec::<u8, 16>::read_from_buffer(&u32::MAX.to_le_bytes()).unwrap_err();
+		assert!(err.to_string().contains("exceeded"));
+	}
+
+	#[cfg(feature = "smallvec")]
+	#[test]
+	fn test_smallvec_conversions() {
+		use smallvec::{SmallVec, smallvec};
+
+		// inline
+		let inline: SmallVec<[u8; 4]> = smallvec![1, 2, 3];
+		assert!(!inline.spilled());
+		assert!(BVec::<u8, 2>::try_from(inline.clone()).is_err());
+		let bvec = BVec::<u8, 3>::try_from(inline).unwrap();
+		assert_eq!(bvec, [1, 2, 3]);
+
+		// spilled
+		let spilled: SmallVec<[u8; 2]> = smallvec![1, 2, 3, 4, 5];
+		assert!(spilled.spilled());
+		let ptr = spilled.as_ptr();
+		assert!(BVec::<u8, 4>::try_from(spilled.clone()).is_err());
+		let bvec = BVec::<u8, 5>::try_from(spilled).unwrap();
+		assert_eq!(bvec.as_ptr(), ptr);
+
+		let ptr = bvec.as_ptr();
+		let back: SmallVec<[u8; 2]> = bvec.into();
+		assert!(back.spilled());
+		assert_eq!(back.as_ptr(), ptr);
+		assert_eq!(&back[..], [1, 2, 3, 4, 5]);
+	}
+
+	#[test]
+	fn test_deduped() {
+		let v: BVec<u8, 8> = bvec![1, 1, 2, 3, 3, 3, 1, 2];
+		let deduped = v.deduped();
+		let mut in_place = v.clone();
+		in_place.dedup();
+		assert_eq!(deduped, in_place);
+		assert_eq!(deduped, [1, 2, 3, 1, 2]);
+		assert_eq!(v.len(), 8);
+		assert!(BVec::<u8, 8>::new().deduped().is_empty());
+	}
+
+	#[test]
+	fn test_insert_slice() {
+		let mut v: BVec<u8, 8> = bvec![1, 2];
+		v.insert_slice(0, &[10, 11]).unwrap();
+		assert_eq!(v, [10, 11, 1, 2]);
+		v.insert_slice(3, &[20]).unwrap();
+		assert_eq!(v, [10, 11, 1, 20, 2]);
+		v.insert_slice(5, &[30, 31]).unwrap();
+		assert_eq!(v, [10, 11, 1, 20, 2, 30, 31]);
+		v.insert_slice(2, &[]).unwrap();
+
+		let err = v.insert_slice(1, &[40, 41]).unwrap_err();
+		assert_eq!((err.length, err.maximum), (9, 8));
+		assert_eq!(v, [10, 11, 1, 20, 2, 30, 31]);
+	}
+
+	#[test]
+	#[should_panic = "insertion index"]
+	fn test_insert_slice_out_of_range() {
+		let mut v: BVec<u8, 8> = bvec![1, 2];
+		let _ = v.insert_slice(3, &[1]);
+	}
+
+	#[cfg(feature = "valuable")]
+	#[test]
+	fn test_valuable() {
+		use alloc::vec::Vec;
+		use valuable::{Valuable, Value, Visit};
+
+		struct Collect(Vec<u32>);
+		impl Visit for Collect {
+			fn visit_value(&mut self, value: Value<'_>) {
+				match value {
+					Value::Listable(l) => l.visit(self),
+					Value::U32(x) => self.0.push(x),
+					_ => panic!("unexpected value {value:?}"),
+				}
+			}
+		}
+
+		let v: BVec<u32, 4> = bvec![1, 2, 3];
+		let mut c = Collect(Vec::new());
+		valuable::visit(&v, &mut c);
+		assert_eq!(c.0, [1, 2, 3]);
+
+		let Value::Listable(l) = v.as_value() else {
+			panic!("not listable");
+		};
+		assert_eq!(l.size_hint(), (3, Some(3)));
+	}
+
+	#[test]
+	fn test_retain_count() {
+		let mut v: BVec<u8, 8> = bvec![1, 2, 3, 4, 5, 6];
+		let len = v.len();
+		let removed = v.retain_count(|&x| x % 3 != 0);
+		assert_eq!(removed, len - v.len());
+		assert_eq!(removed, 2);
+		assert_eq!(v, [1, 2, 4, 5]);
+		assert_eq!(v.retain_count(|_| true), 0);
+		assert_eq!(v.retain_count(|_| false), 4);
+		assert!(v.is_empty());
+	}
+
+	#[test]
+	fn test_max_serialized_len() {
+		assert_eq!(BVec::<u8, 0>::max_serialized_len(), 1);
+		assert_eq!(BVec::<u8, 127>::max_serialized_len(), 128);
+		assert_eq!(BVec::<u8, 128>::max_serialized_len(), 130);
+		assert_eq!(BVec::<u8, 16384>::max_serialized_len(), 16387);
+		assert_eq!(BString::<127>::max_serialized_len(), 128);
+		assert_eq!(BString::<300, encoding::Cesu8>::max_serialized_len(), 302);
+		assert_eq!(BString::<127, encoding::MCesu8>::max_serialized_len(), 128);
+	}
+
+	#[test]
+	fn test_cstr() {
+		let v = BVec::<core::num::NonZero<u8>, 8>::from_cstr(c"hello").unwrap();
+		assert!(v.iter().map(|b| b.get()).eq(*b"hello"));
+		assert_eq!(v.to_cstring().as_bytes_with_nul(), b"hello\0");
+		assert_eq!(alloc::ffi::CString::from(v).as_c_str(), c"hello");
+
+		let err = BVec::<core::num::NonZero<u8>, 8>::from_cstr(c"").unwrap_err();
+		assert!(matches!(
+			err,
+			LengthOutOfBounds::BelowMinimum {
+				length: 0,
+				minimum: 1
+			}
+		));
+		let err = BVec::<core::num::NonZero<u8>, 8>::from_cstr(c"hello world").unwrap_err();
+		assert!(matches!(
+			err,
+			LengthOutOfBounds::LengthExceeded(e) if (e.length, e.maximum) == (11, 8)
+		));
+	}
+
+	#[test]
+	fn test_cmp_vec_deque() {
+		use core::cmp::Ordering::*;
+
+		let v: BVec<u8, 4> = bvec![1, 2, 3];
+		let equal = VecDeque::from([1, 2, 3]);
+		let shorter = VecDeque::from([1, 2]);
+		let longer = VecDeque::from([1, 2, 3, 0]);
+
+		assert_eq!(v, equal);
+		assert_eq!(equal, v);
+		assert_eq!(*v, equal);
+		assert_ne!(v, shorter);
+		assert_ne!(longer, v);
+		assert_ne!(*v, longer);
+
+		assert_eq!(v.partial_cmp(&equal), Some(Equal));
+		assert_eq!(v.partial_cmp(&shorter), Some(Greater));
+		assert_eq!(v.partial_cmp(&longer), Some(Less));
+		assert_eq!(longer.partial_cmp(&v), Some(Greater));
+		assert_eq!((*v).partial_cmp(&shorter), Some(Greater));
+		assert_eq!(shorter.partial_cmp(&*v), Some(Less));
+	}
 }
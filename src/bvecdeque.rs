@@ -0,0 +1,356 @@
+use alloc::collections::{VecDeque, vec_deque};
+use core::{
+	borrow::Borrow,
+	ops::{Deref, RangeBounds},
+};
+
+use crate::{BSlice, BVec, LengthExceeded};
+
+/// Bounded [`VecDeque`].
+///
+/// Guaranteed to not be longer than `MAX` elements.
+#[derive(Debug, Default, Hash)]
+pub struct BVecDeque<T, const MAX: usize> {
+	s: VecDeque<T>,
+}
+
+impl<T, const MAX: usize> BVecDeque<T, MAX> {
+	/// Creates a `BVecDeque<T, MAX>` from a `VecDeque<T>` without any checks.
+	///
+	/// # Safety
+	///
+	/// The caller is responsible for making sure that the deque is definitely not longer than `MAX` elements.
+	pub const unsafe fn from_vec_deque_unchecked(s: VecDeque<T>) -> Self {
+		Self { s }
+	}
+	/// Creates a `BVecDeque<T, MAX>` from a `VecDeque<T>`, performing a runtime check.
+	pub fn from_vec_deque(s: VecDeque<T>) -> Result<Self, LengthExceeded> {
+		if s.len() > MAX {
+			return Err(LengthExceeded {
+				length: s.len(),
+				maximum: MAX,
+				encoding: LengthExceeded::ELEMENTS,
+			});
+		}
+
+		Ok(unsafe { Self::from_vec_deque_unchecked(s) })
+	}
+	/// Gives the inner [`VecDeque<T>`].
+	pub fn into_inner(self) -> VecDeque<T> {
+		self.s
+	}
+	/// Creates an empty deque.
+	///
+	/// See [`VecDeque::new`] for more information.
+	pub const fn new() -> Self {
+		Self { s: VecDeque::new() }
+	}
+	/// Creates an empty deque with space for at least `capacity` elements.
+	///
+	/// See [`VecDeque::with_capacity`] for more information.
+	pub fn with_capacity(capacity: usize) -> Self {
+		Self {
+			s: VecDeque::with_capacity(capacity),
+		}
+	}
+	/// Appends an element to the back of the deque, performing a runtime check.
+	///
+	/// See [`VecDeque::push_back`] for more information.
+	pub fn push_back(&mut self, value: T) -> Result<(), LengthExceeded> {
+		self.check_push()?;
+		self.s.push_back(value);
+
+		Ok(())
+	}
+	/// Prepends an element to the deque, performing a runtime check.
+	///
+	/// See [`VecDeque::push_front`] for more information.
+	pub fn push_front(&mut self, value: T) -> Result<(), LengthExceeded> {
+		self.check_push()?;
+		self.s.push_front(value);
+
+		Ok(())
+	}
+	fn check_push(&self) -> Result<(), LengthExceeded> {
+		if self.s.len() >= MAX {
+			return Err(LengthExceeded {
+				length: self.s.len() + 1,
+				maximum: MAX,
+				encoding: LengthExceeded::ELEMENTS,
+			});
+		}
+
+		Ok(())
+	}
+	/// Removes the last element and returns it, or `None` if the deque is empty.
+	///
+	/// See [`VecDeque::pop_back`] for more information.
+	pub fn pop_back(&mut self) -> Option<T> {
+		self.s.pop_back()
+	}
+	/// Removes the first element and returns it, or `None` if the deque is empty.
+	///
+	/// See [`VecDeque::pop_front`] for more information.
+	pub fn pop_front(&mut self) -> Option<T> {
+		self.s.pop_front()
+	}
+	/// Provides a mutable reference to the front element, or `None` if the deque is empty.
+	///
+	/// See [`VecDeque::front_mut`] for more information.
+	pub fn front_mut(&mut self) -> Option<&mut T> {
+		self.s.front_mut()
+	}
+	/// Provides a mutable reference to the back element, or `None` if the deque is empty.
+	///
+	/// See [`VecDeque::back_mut`] for more information.
+	pub fn back_mut(&mut self) -> Option<&mut T> {
+		self.s.back_mut()
+	}
+	/// Provides a mutable reference to the element at the given index.
+	///
+	/// See [`VecDeque::get_mut`] for more information.
+	pub fn get_mut(&mut self, index: usize) -> Option<&mut T> {
+		self.s.get_mut(index)
+	}
+	/// Returns a front-to-back iterator that returns mutable references.
+	///
+	/// See [`VecDeque::iter_mut`] for more information.
+	pub fn iter_mut(&mut self) -> vec_deque::IterMut<'_, T> {
+		self.s.iter_mut()
+	}
+	/// Returns a pair of bounded slices which contain, in order, the contents of the deque.
+	///
+	/// See [`VecDeque::as_slices`] for more information.
+	pub fn as_bslices(&self) -> (&BSlice<T, MAX>, &BSlice<T, MAX>) {
+		let (a, b) = self.s.as_slices();
+
+		// both halves together are not longer than `MAX`
+		unsafe {
+			(
+				BSlice::from_slice_unchecked(a),
+				BSlice::from_slice_unchecked(b),
+			)
+		}
+	}
+	/// Returns a pair of mutable bounded slices which contain, in order, the contents of the deque.
+	///
+	/// See [`VecDeque::as_mut_slices`] for more information.
+	pub fn as_mut_bslices(&mut self) -> (&mut BSlice<T, MAX>, &mut BSlice<T, MAX>) {
+		let (a, b) = self.s.as_mut_slices();
+
+		unsafe {
+			(
+				BSlice::from_slice_mut_unchecked(a),
+				BSlice::from_slice_mut_unchecked(b),
+			)
+		}
+	}
+	/// Rearranges the internal storage of this deque so it is one contiguous bounded slice.
+	///
+	/// See [`VecDeque::make_contiguous`] for more information.
+	pub fn make_contiguous(&mut self) -> &mut BSlice<T, MAX> {
+		unsafe { BSlice::from_slice_mut_unchecked(self.s.make_contiguous()) }
+	}
+	/// Removes and returns the element at `index` from the deque.
+	///
+	/// See [`VecDeque::remove`] for more information.
+	pub fn remove(&mut self, index: usize) -> Option<T> {
+		self.s.remove(index)
+	}
+	/// Swaps elements at indices `i` and `j`.
+	///
+	/// See [`VecDeque::swap`] for more information.
+	pub fn swap(&mut self, i: usize, j: usize) {
+		self.s.swap(i, j)
+	}
+	/// Removes the specified range from the deque in bulk, returning all removed elements as an iterator.
+	///
+	/// See [`VecDeque::drain`] for more information.
+	pub fn drain<R: RangeBounds<usize>>(&mut self, range: R) -> vec_deque::Drain<'_, T> {
+		self.s.drain(range)
+	}
+	/// Clears the deque, removing all values.
+	///
+	/// See [`VecDeque::clear`] for more information.
+	pub fn clear(&mut self) {
+		self.s.clear()
+	}
+	/// Shortens the deque, keeping the first `len` elements and dropping the rest.
+	///
+	/// See [`VecDeque::truncate`] for more information.
+	pub fn truncate(&mut self, len: usize) {
+		self.s.truncate(len)
+	}
+	/// Retains only the elements specified by the predicate.
+	///
+	/// See [`VecDeque::retain`] for more information.
+	pub fn retain(&mut self, f: impl FnMut(&T) -> bool) {
+		self.s.retain(f)
+	}
+	/// Rotates the double-ended queue `n` places to the left.
+	///
+	/// See [`VecDeque::rotate_left`] for more information.
+	pub fn rotate_left(&mut self, n: usize) {
+		self.s.rotate_left(n)
+	}
+	/// Rotates the double-ended queue `n` places to the right.
+	///
+	/// See [`VecDeque::rotate_right`] for more information.
+	pub fn rotate_right(&mut self, n: usize) {
+		self.s.rotate_right(n)
+	}
+	/// Shrinks the capacity of the deque as much as possible.
+	///
+	/// See [`VecDeque::shrink_to_fit`] for more information.
+	pub fn shrink_to_fit(&mut self) {
+		self.s.shrink_to_fit()
+	}
+}
+
+// Trait implementations relating BVecDeque and BVec
+/////////////////////////////////////////////////////
+
+impl<T, const MAX: usize> From<BVec<T, MAX>> for BVecDeque<T, MAX> {
+	fn from(value: BVec<T, MAX>) -> Self {
+		unsafe { Self::from_vec_deque_unchecked(value.into_inner().into()) }
+	}
+}
+impl<T, const MAX: usize> From<BVecDeque<T, MAX>> for BVec<T, MAX> {
+	fn from(value: BVecDeque<T, MAX>) -> Self {
+		unsafe { BVec::from_vec_unchecked(value.s.into()) }
+	}
+}
+
+// Trait implementations mirroring standard VecDeque
+////////////////////////////////////////////////////
+
+impl<T, const MAX: usize> Deref for BVecDeque<T, MAX> {
+	type Target = VecDeque<T>;
+
+	fn deref(&self) -> &Self::Target {
+		&self.s
+	}
+}
+impl<T, const MAX: usize> AsRef<VecDeque<T>> for BVecDeque<T, MAX> {
+	fn as_ref(&self) -> &VecDeque<T> {
+		&self.s
+	}
+}
+impl<T, const MAX: usize> Borrow<VecDeque<T>> for BVecDeque<T, MAX> {
+	fn borrow(&self) -> &VecDeque<T> {
+		&self.s
+	}
+}
+impl<T: Clone, const MAX: usize> Clone for BVecDeque<T, MAX> {
+	fn clone(&self) -> Self {
+		Self { s: self.s.clone() }
+	}
+}
+impl<T, const MAX: usize> TryFrom<VecDeque<T>> for BVecDeque<T, MAX> {
+	type Error = LengthExceeded;
+
+	fn try_from(value: VecDeque<T>) -> Result<Self, Self::Error> {
+		Self::from_vec_deque(value)
+	}
+}
+impl<T, const MAX: usize> From<BVecDeque<T, MAX>> for VecDeque<T> {
+	fn from(value: BVecDeque<T, MAX>) -> Self {
+		value.s
+	}
+}
+impl<T, const MAX: usize> IntoIterator for BVecDeque<T, MAX> {
+	type Item = T;
+	type IntoIter = vec_deque::IntoIter<T>;
+
+	fn into_iter(self) -> Self::IntoIter {
+		self.s.into_iter()
+	}
+}
+impl<'a, T, const MAX: usize> IntoIterator for &'a BVecDeque<T, MAX> {
+	type Item = &'a T;
+	type IntoIter = vec_deque::Iter<'a, T>;
+
+	fn into_iter(self) -> Self::IntoIter {
+		self.s.iter()
+	}
+}
+impl<'a, T, const MAX: usize> IntoIterator for &'a mut BVecDeque<T, MAX> {
+	type Item = &'a mut T;
+	type IntoIter = vec_deque::IterMut<'a, T>;
+
+	fn into_iter(self) -> Self::IntoIter {
+		self.s.iter_mut()
+	}
+}
+impl<T: PartialEq<U>, U, const MAX1: usize, const MAX2: usize> PartialEq<BVecDeque<U, MAX2>>
+	for BVecDeque<T, MAX1>
+{
+	fn eq(&self, other: &BVecDeque<U, MAX2>) -> bool {
+		self.len() == other.len() && self.iter().zip(other.iter()).all(|(a, b)| a == b)
+	}
+}
+impl<T: Eq, const MAX: usize> Eq for BVecDeque<T, MAX> {}
+impl<T: PartialEq<U>, U, const MAX: usize> PartialEq<VecDeque<U>> for BVecDeque<T, MAX> {
+	fn eq(&self, other: &VecDeque<U>) -> bool {
+		self.len() == other.len() && self.iter().zip(other).all(|(a, b)| a == b)
+	}
+}
+impl<T: PartialEq<U>, U, const MAX: usize, const N: usize> PartialEq<[U; N]> for BVecDeque<T, MAX> {
+	fn eq(&self, other: &[U; N]) -> bool {
+		self.s.eq(other)
+	}
+}
+impl<T: PartialOrd, const MAX: usize> PartialOrd for BVecDeque<T, MAX> {
+	fn partial_cmp(&self, other: &Self) -> Option<core::cmp::Ordering> {
+		self.s.partial_cmp(&other.s)
+	}
+}
+impl<T: Ord, const MAX: usize> Ord for BVecDeque<T, MAX> {
+	fn cmp(&self, other: &Self) -> core::cmp::Ordering {
+		self.s.cmp(&other.s)
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use crate::*;
+
+	#[test]
+	fn test_push_both_ends() {
+		let mut d = BVecDeque::<u8, 4>::with_capacity(4);
+		d.push_back(2).unwrap();
+		d.push_front(1).unwrap();
+		d.push_back(3).unwrap();
+		d.push_front(0).unwrap();
+		assert_eq!(d, [0, 1, 2, 3]);
+		assert!(d.push_back(4).is_err());
+		assert!(d.push_front(4).is_err());
+
+		// wrap around in the ring buffer, hitting MAX at the back
+		for i in 4..20 {
+			assert_eq!(d.pop_front(), Some(i - 4));
+			d.push_back(i).unwrap();
+			assert_eq!(d.push_back(i).unwrap_err().length, 5);
+		}
+		assert_eq!(d, [16, 17, 18, 19]);
+
+		// and the other way around, hitting MAX at the front
+		for i in (0..16).rev() {
+			assert_eq!(d.pop_back(), Some(i + 4));
+			d.push_front(i).unwrap();
+			assert_eq!(d.push_front(i).unwrap_err().length, 5);
+		}
+		assert_eq!(d, [0, 1, 2, 3]);
+
+		let (a, b) = d.as_bslices();
+		assert_eq!(a.len() + b.len(), 4);
+		assert_eq!(BVec::from(d.clone()), d.make_contiguous());
+	}
+
+	#[test]
+	fn test_from_vec_deque() {
+		let v: alloc::collections::VecDeque<u8> = (0..5).collect();
+		assert!(BVecDeque::<u8, 4>::from_vec_deque(v.clone()).is_err());
+		assert_eq!(BVecDeque::<u8, 5>::try_from(v.clone()).unwrap(), v);
+	}
+}
@@ -1,9 +1,12 @@
 use super::Encoding;
+use alloc::vec::Vec;
 
 /// The [CESU-8](https://en.wikipedia.org/wiki/CESU-8) encoding
 #[derive(Debug, Default, Hash)]
 pub struct Cesu8;
 impl Encoding for Cesu8 {
+	const NAME: &'static str = "CESU-8";
+
 	fn length(s: &str) -> usize {
 		let mut extra = 0;
 		for c in s.chars() {
@@ -14,4 +17,28 @@ impl Encoding for Cesu8 {
 
 		s.len() + extra
 	}
+	fn encode(s: &str) -> Vec<u8> {
+		let mut out = Vec::with_capacity(Self::length(s));
+		for c in s.chars() {
+			encode_char(c, &mut out);
+		}
+
+		out
+	}
+}
+
+/// Encodes a single char in CESU-8, splitting characters outside the BMP into
+/// a UTF-16 surrogate pair with each surrogate encoded as a 3-byte sequence.
+pub(super) fn encode_char(c: char, out: &mut Vec<u8>) {
+	if c > '\u{FFFF}' {
+		for &unit in c.encode_utf16(&mut [0; 2]).iter() {
+			out.extend_from_slice(&[
+				0xE0 | (unit >> 12) as u8,
+				0x80 | ((unit >> 6) & 0x3F) as u8,
+				0x80 | (unit & 0x3F) as u8,
+			]);
+		}
+	} else {
+		out.extend_from_slice(c.encode_utf8(&mut [0; 4]).as_bytes());
+	}
 }
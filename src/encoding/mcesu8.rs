@@ -1,9 +1,12 @@
-use super::Encoding;
+use super::{Encoding, cesu8};
+use alloc::vec::Vec;
 
 /// The [Modified CESU-8](https://en.wikipedia.org/wiki/CESU-8) encoding (same as CESU-8 but encodes `00` as `C0 80`)
 #[derive(Debug, Default, Hash)]
 pub struct MCesu8;
 impl Encoding for MCesu8 {
+	const NAME: &'static str = "Modified CESU-8";
+
 	fn length(s: &str) -> usize {
 		let mut extra = 0;
 		for c in s.chars() {
@@ -17,4 +20,16 @@ impl Encoding for MCesu8 {
 
 		s.len() + extra
 	}
+	fn encode(s: &str) -> Vec<u8> {
+		let mut out = Vec::with_capacity(Self::length(s));
+		for c in s.chars() {
+			if c == '\u{0}' {
+				out.extend_from_slice(&[0xC0, 0x80]);
+			} else {
+				cesu8::encode_char(c, &mut out);
+			}
+		}
+
+		out
+	}
 }
@@ -1,3 +1,5 @@
+use alloc::vec::Vec;
+
 mod cesu8;
 mod mcesu8;
 mod utf8;
@@ -11,7 +13,32 @@ mod utf8;
 /// - Converting an ASCII character from lowercase to uppercase and vice versa will **never** change the length of the string in that encoding.
 ///
 pub trait Encoding {
+	/// Human-readable name of the encoding, used in error messages.
+	///
+	/// Defaults to `"custom"`, so that encodings written before this constant existed keep compiling.
+	const NAME: &'static str = "custom";
+	/// Whether the representation in this encoding is byte-for-byte the same as UTF-8.
+	///
+	/// If `true`, [`encode`](Self::encode) must be equivalent to [`str::as_bytes`].
+	const BYTES_EQUAL_UTF8: bool = false;
+
 	fn length(s: &str) -> usize;
+	/// Encodes the string into its byte representation in this encoding.
+	///
+	/// The result must be exactly [`length`](Self::length) bytes long.
+	///
+	/// The default implementation copies the UTF-8 bytes and is only correct for encodings
+	/// with [`BYTES_EQUAL_UTF8`](Self::BYTES_EQUAL_UTF8), so that encodings written before
+	/// this method existed keep compiling. It panics for all other encodings, which must override it.
+	fn encode(s: &str) -> Vec<u8> {
+		assert!(
+			Self::BYTES_EQUAL_UTF8,
+			"the {} encoding doesn't implement Encoding::encode",
+			Self::NAME
+		);
+
+		s.as_bytes().to_vec()
+	}
 }
 
 pub use cesu8::Cesu8;
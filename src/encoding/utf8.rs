@@ -4,6 +4,9 @@ use super::Encoding;
 #[derive(Debug, Default, Hash)]
 pub struct Utf8;
 impl Encoding for Utf8 {
+	const NAME: &'static str = "UTF-8";
+	const BYTES_EQUAL_UTF8: bool = true;
+
 	fn length(s: &str) -> usize {
 		s.len()
 	}
@@ -1,9 +1,116 @@
+#[cfg(feature = "std")]
+use std::io;
 use thiserror::Error;
 
 /// Length exceeded error.
 #[derive(Error, Debug)]
-#[error("length of {length} exceeded ({maximum})")]
+#[error("{encoding} length {length} exceeded maximum of {maximum} (over by {})", self.overflow_by())]
+#[non_exhaustive]
 pub struct LengthExceeded {
 	pub length: usize,
 	pub maximum: usize,
+	/// The unit of `length` and `maximum`: the name of the [`Encoding`](crate::encoding::Encoding)
+	/// for strings, or [`LengthExceeded::ELEMENTS`] for slices and vectors.
+	pub encoding: &'static str,
+}
+
+impl LengthExceeded {
+	/// The [`encoding`](LengthExceeded::encoding) used for slices and vectors.
+	pub const ELEMENTS: &'static str = "elements";
+
+	/// Creates a new `LengthExceeded` error.
+	pub const fn new(length: usize, maximum: usize, encoding: &'static str) -> Self {
+		Self {
+			length,
+			maximum,
+			encoding,
+		}
+	}
+	/// Returns by how much the length exceeded the maximum.
+	///
+	/// This is 0 if the error was constructed with a length that doesn't exceed the maximum.
+	pub fn overflow_by(&self) -> usize {
+		self.length.saturating_sub(self.maximum)
+	}
+}
+
+/// Error for a length that is bounded from both sides, such as constructing a [`BMinVec`](crate::BMinVec).
+#[derive(Error, Debug)]
+#[non_exhaustive]
+pub enum LengthOutOfBounds {
+	/// There are fewer elements than the minimum.
+	#[error("length {length} is below minimum of {minimum}")]
+	BelowMinimum { length: usize, minimum: usize },
+	/// There are more elements than the maximum.
+	#[error(transparent)]
+	LengthExceeded(#[from] LengthExceeded),
+}
+
+/// Error converting a [`Path`](std::path::Path) into a bounded string.
+#[cfg(feature = "std")]
+#[derive(Error, Debug)]
+#[non_exhaustive]
+pub enum FromPathError {
+	/// The path is not valid UTF-8.
+	#[error("path is not valid UTF-8")]
+	NotUtf8,
+	/// The path is too long.
+	#[error(transparent)]
+	LengthExceeded(#[from] LengthExceeded),
+}
+
+#[cfg(feature = "std")]
+impl From<LengthExceeded> for io::Error {
+	fn from(value: LengthExceeded) -> Self {
+		io::Error::new(io::ErrorKind::InvalidData, value)
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use crate::{encoding::Cesu8, *};
+	use alloc::string::ToString;
+
+	#[test]
+	fn test_display() {
+		let err = BStr::<16, Cesu8>::from_str("a string that is 30 bytes long").unwrap_err();
+		assert_eq!(err.overflow_by(), 14);
+		assert_eq!(
+			err.to_string(),
+			"CESU-8 length 30 exceeded maximum of 16 (over by 14)"
+		);
+
+		let err = BSlice::<u8, 2>::from_slice(&[1, 2, 3]).unwrap_err();
+		assert_eq!(
+			err.to_string(),
+			"elements length 3 exceeded maximum of 2 (over by 1)"
+		);
+	}
+
+	#[test]
+	fn test_overflow_by_not_exceeded() {
+		let err = LengthExceeded::new(2, 4, LengthExceeded::ELEMENTS);
+		assert_eq!(err.overflow_by(), 0);
+		assert_eq!(
+			err.to_string(),
+			"elements length 2 exceeded maximum of 4 (over by 0)"
+		);
+	}
+
+	#[cfg(feature = "std")]
+	#[test]
+	fn test_io_error() {
+		fn f() -> std::io::Result<()> {
+			BSlice::<u8, 2>::from_slice(&[1, 2, 3])?;
+			Ok(())
+		}
+
+		let err = f().unwrap_err();
+		assert_eq!(err.kind(), std::io::ErrorKind::InvalidData);
+		assert_eq!(
+			err.to_string(),
+			"elements length 3 exceeded maximum of 2 (over by 1)"
+		);
+		assert!(err.into_inner().unwrap().is::<LengthExceeded>());
+	}
 }
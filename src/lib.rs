@@ -1,22 +1,48 @@
 #![doc = include_str!("../README.md")]
+#![no_std]
 
+extern crate alloc;
+#[cfg(any(feature = "std", test))]
+extern crate std;
+
+#[cfg(feature = "std")]
+mod bcursor;
+mod bminvec;
 mod bslice;
 mod bstr;
 mod bstring;
 mod bvec;
+mod bvecdeque;
 mod error;
 
 /// For macro usage
 #[doc(hidden)]
 pub mod const_checks;
+/// For macro usage
+#[doc(hidden)]
+pub use alloc::vec as __vec;
 /// Different string encodings
 pub mod encoding;
+/// [`proptest`](::proptest) strategies for the bounded types
+#[cfg(feature = "proptest")]
+pub mod proptest;
 
+#[cfg(feature = "std")]
+pub use bcursor::BCursor;
+pub use bminvec::BMinVec;
 pub use bslice::BSlice;
-pub use bstr::BStr;
+pub use bstr::{BStr, DisplayEncoded, IntoChars};
 pub use bstring::BString;
 pub use bvec::BVec;
-pub use error::LengthExceeded;
+pub use bvecdeque::BVecDeque;
+#[cfg(feature = "std")]
+pub use error::FromPathError;
+pub use error::{LengthExceeded, LengthOutOfBounds};
+
+#[cfg(feature = "rkyv")]
+pub use bstring::ArchivedBString;
+#[cfg(feature = "rkyv")]
+pub use bvec::ArchivedBVec;
 
 /// Creates a static `&'static BStr<MAX, E>` with a compile-time check.
 ///
@@ -0,0 +1,66 @@
+use crate::{BSlice, BString, BVec, encoding::Encoding};
+use ::proptest::{collection, prelude::*};
+use alloc::{boxed::Box, string::String};
+use core::fmt::Debug;
+
+/// Creates a strategy that generates a [`BVec<T, MAX>`] with elements from the given strategy.
+///
+/// Shrinks towards an empty vector.
+pub fn bvec_strategy<S: Strategy, const MAX: usize>(
+	element: S,
+) -> impl Strategy<Value = BVec<S::Value, MAX>> {
+	collection::vec(element, 0..=MAX).prop_map(|v| unsafe { BVec::from_vec_unchecked(v) })
+}
+
+/// Creates a strategy that generates a [`Box<BSlice<T, MAX>>`] with elements from the given strategy.
+///
+/// Shrinks towards an empty slice.
+pub fn boxed_bslice_strategy<S: Strategy, const MAX: usize>(
+	element: S,
+) -> impl Strategy<Value = Box<BSlice<S::Value, MAX>>> {
+	bvec_strategy(element).prop_map(BVec::into_boxed_slice)
+}
+
+/// Creates a strategy that generates a [`BString<MAX, E>`] with arbitrary characters.
+///
+/// Shrinks towards an empty string.
+///
+/// ```
+/// # use maxlen::{encoding::Cesu8, proptest::bstring_strategy};
+/// # use proptest::prelude::*;
+/// proptest!(|(s in bstring_strategy::<Cesu8, 16>())| {
+///     prop_assert!(s.encoded_len() <= 16);
+/// });
+/// ```
+pub fn bstring_strategy<E: Encoding + Debug, const MAX: usize>()
+-> impl Strategy<Value = BString<MAX, E>> {
+	bstring_strategy_with(any::<char>())
+}
+
+/// Creates a strategy that generates a [`BString<MAX, E>`] with characters from the given strategy.
+///
+/// Shrinks towards an empty string.
+pub fn bstring_strategy_with<E: Encoding + Debug, const MAX: usize>(
+	c: impl Strategy<Value = char>,
+) -> impl Strategy<Value = BString<MAX, E>> {
+	// every character takes up at least one byte in the provided encodings,
+	// so there's no point in generating more than MAX of them
+	collection::vec(c, 0..=MAX).prop_map(|chars| {
+		let mut s = String::new();
+		let mut length = 0;
+		for c in chars {
+			if length == MAX {
+				break;
+			}
+			// skip characters that don't fit, a narrower one later on still might
+			let char_length = E::length(c.encode_utf8(&mut [0; 4]));
+			if char_length > MAX - length {
+				continue;
+			}
+			length += char_length;
+			s.push(c);
+		}
+
+		unsafe { BString::from_string_unchecked(s) }
+	})
+}
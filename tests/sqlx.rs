@@ -0,0 +1,35 @@
+use maxlen::{BString, BVec, bvec};
+use sqlx::{Connection, SqliteConnection};
+
+#[tokio::test]
+async fn test_sqlx() {
+	let mut conn = SqliteConnection::connect("sqlite::memory:").await.unwrap();
+
+	let bvec: BVec<u8, 4> = bvec![1, 2, 3, 4];
+	let row: (BVec<u8, 4>,) = sqlx::query_as("SELECT ?")
+		.bind(&bvec)
+		.fetch_one(&mut conn)
+		.await
+		.unwrap();
+	assert_eq!(row.0, bvec);
+	let err = sqlx::query_as::<_, (BVec<u8, 3>,)>("SELECT ?")
+		.bind(&bvec)
+		.fetch_one(&mut conn)
+		.await
+		.unwrap_err();
+	assert!(err.to_string().contains("exceeded"));
+
+	let bstring = BString::<5>::from_str("hello").unwrap();
+	let row: (BString<5>,) = sqlx::query_as("SELECT ?")
+		.bind(&bstring)
+		.fetch_one(&mut conn)
+		.await
+		.unwrap();
+	assert_eq!(row.0, bstring);
+	let err = sqlx::query_as::<_, (BString<4>,)>("SELECT ?")
+		.bind(&bstring)
+		.fetch_one(&mut conn)
+		.await
+		.unwrap_err();
+	assert!(err.to_string().contains("exceeded"));
+}